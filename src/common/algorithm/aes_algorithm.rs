@@ -1,6 +1,141 @@
-use openssl::symm::{decrypt, encrypt, Cipher};
+use openssl::rand::rand_bytes;
+use openssl::symm::{decrypt, decrypt_aead, encrypt, encrypt_aead, Cipher};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
 use std::error::Error;
 
+/// GCM 认证标签长度（字节）
+/// GCM authentication tag length in bytes
+const GCM_TAG_LEN: usize = 16;
+
+/// AES 工作模式
+/// AES block cipher mode of operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AesMode {
+    /// 电子密码本模式，PKCS7填充，无IV，仅用于兼容旧数据
+    /// Electronic codebook with PKCS7 padding and no IV, kept for legacy data only
+    Ecb,
+    /// 密码分组链接模式，PKCS7填充，16字节IV
+    /// Cipher block chaining with PKCS7 padding and a 16-byte IV
+    Cbc,
+    /// 计数器模式，无填充，16字节初始计数器
+    /// Counter mode without padding and a 16-byte initial counter block
+    Ctr,
+    /// 伽罗瓦/计数器模式，12字节随机数，16字节认证标签，支持关联数据
+    /// Galois/counter mode with a 12-byte nonce, a 16-byte tag and associated data
+    Gcm,
+}
+
+impl AesMode {
+    /// 获取该模式所需的IV/随机数长度
+    /// Get the IV/nonce length required by this mode
+    pub fn iv_len(&self) -> usize {
+        match self {
+            AesMode::Ecb => 0,
+            AesMode::Cbc | AesMode::Ctr => 16,
+            AesMode::Gcm => 12,
+        }
+    }
+
+    /// 该模式是否提供完整性校验
+    /// Whether this mode provides an integrity check
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, AesMode::Gcm)
+    }
+
+    /// 模式在二进制信封中的标识字节
+    /// Identifier byte of the mode in the binary envelope
+    fn to_byte(self) -> u8 {
+        match self {
+            AesMode::Ecb => 0,
+            AesMode::Cbc => 1,
+            AesMode::Ctr => 2,
+            AesMode::Gcm => 3,
+        }
+    }
+
+    /// 从标识字节解析模式
+    /// Parse the mode from its identifier byte
+    fn from_byte(byte: u8) -> Result<Self, Box<dyn Error>> {
+        match byte {
+            0 => Ok(AesMode::Ecb),
+            1 => Ok(AesMode::Cbc),
+            2 => Ok(AesMode::Ctr),
+            3 => Ok(AesMode::Gcm),
+            _ => Err(format!("未知的AES模式标识: {}", byte).into()),
+        }
+    }
+}
+
+/// 自描述的AES密文信封，包含模式、IV/随机数、认证标签和密文
+/// Self-describing AES ciphertext envelope holding the mode, IV/nonce, tag and ciphertext
+///
+/// JSON 序列化时字节字段使用十六进制字符串；`to_bytes`/`from_bytes` 提供紧凑的二进制格式：
+/// `mode(1) | iv_len(1) | iv | tag_len(1) | tag | ciphertext`。
+///
+/// Byte fields are hex strings when serialised to JSON; `to_bytes`/`from_bytes` provide a
+/// compact binary layout: `mode(1) | iv_len(1) | iv | tag_len(1) | tag | ciphertext`.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AesCiphertext {
+    /// 加密模式
+    /// Cipher mode
+    pub mode: AesMode,
+    /// IV 或随机数，ECB 模式下为空
+    /// IV or nonce, empty for ECB
+    #[serde_as(as = "Hex")]
+    pub iv: Vec<u8>,
+    /// 认证标签，仅 GCM 模式非空
+    /// Authentication tag, only non-empty for GCM
+    #[serde_as(as = "Hex")]
+    pub tag: Vec<u8>,
+    /// 密文
+    /// Ciphertext
+    #[serde_as(as = "Hex")]
+    pub ciphertext: Vec<u8>,
+}
+
+impl AesCiphertext {
+    /// 将信封编码为紧凑的二进制格式
+    /// Encode the envelope into the compact binary layout
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(3 + self.iv.len() + self.tag.len() + self.ciphertext.len());
+        bytes.push(self.mode.to_byte());
+        bytes.push(self.iv.len() as u8);
+        bytes.extend_from_slice(&self.iv);
+        bytes.push(self.tag.len() as u8);
+        bytes.extend_from_slice(&self.tag);
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// 从紧凑的二进制格式解析信封
+    /// Parse the envelope from the compact binary layout
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let truncated = || -> Box<dyn Error> { "AES密文信封长度不足".into() };
+        let (&mode, rest) = bytes.split_first().ok_or_else(truncated)?;
+        let mode = AesMode::from_byte(mode)?;
+        let (&iv_len, rest) = rest.split_first().ok_or_else(truncated)?;
+        if rest.len() < iv_len as usize {
+            return Err(truncated());
+        }
+        let (iv, rest) = rest.split_at(iv_len as usize);
+        let (&tag_len, rest) = rest.split_first().ok_or_else(truncated)?;
+        if rest.len() < tag_len as usize {
+            return Err(truncated());
+        }
+        let (tag, ciphertext) = rest.split_at(tag_len as usize);
+        Ok(AesCiphertext {
+            mode,
+            iv: iv.to_vec(),
+            tag: tag.to_vec(),
+            ciphertext: ciphertext.to_vec(),
+        })
+    }
+}
+
 /// AES 加密/解密算法工具类
 /// AES encryption/decryption algorithm utility class
 pub struct AESAlgorithm;
@@ -9,6 +144,12 @@ impl AESAlgorithm {
     /// 使用AES ECB模式加密数据，使用PKCS7填充。
     /// Encrypt data using AES ECB mode with PKCS7 padding.
     ///
+    /// 已弃用：ECB 会泄露明文模式且没有完整性校验，新数据请使用 `encrypt` 并选择 `AesMode::Gcm`；
+    /// 旧数据可用 `encrypt(AesMode::Ecb, ..)` 显式选择 ECB。
+    /// Deprecated: ECB leaks plaintext patterns and has no integrity check; use `encrypt` with
+    /// `AesMode::Gcm` for new data, or pick ECB explicitly with `encrypt(AesMode::Ecb, ..)` for
+    /// legacy data.
+    ///
     /// # 参数
     /// * `key`: 密钥，长度必须为16、24或32字节（分别对应AES-128、AES-192、AES-256）。
     /// * `data`: 要加密的明文数据。
@@ -19,6 +160,7 @@ impl AESAlgorithm {
     /// * `data`: Plaintext data to be encrypted.
     /// # Returns
     /// * Returns the encrypted ciphertext byte vector on success, or an error on failure.
+    #[deprecated(note = "use encrypt/decrypt with AesMode")]
     pub fn encode(key: &[u8], data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
         let cipher = Self::get_aes_cipher(key)?;
         Ok(encrypt(cipher, key, None, data)?)
@@ -27,6 +169,9 @@ impl AESAlgorithm {
     /// 使用AES ECB模式解密数据，处理PKCS7填充。
     /// Decrypt data using AES ECB mode, handling PKCS7 padding.
     ///
+    /// 已弃用：请使用 `decrypt`，由密文信封中的 `AesMode` 决定模式。
+    /// Deprecated: use `decrypt`, which takes the mode from the envelope's `AesMode`.
+    ///
     /// # 参数
     /// * `key`: 密钥，必须与加密时使用的密钥相同。
     /// * `encrypted_data`: 要解密的密文字节向量。
@@ -37,11 +182,182 @@ impl AESAlgorithm {
     /// * `encrypted_data`: Ciphertext byte vector to be decrypted.
     /// # Returns
     /// * Returns the decrypted plaintext byte vector on success, or an error on failure.
+    #[deprecated(note = "use encrypt/decrypt with AesMode")]
     pub fn decode(key: &[u8], encrypted_data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
         let cipher = Self::get_aes_cipher(key)?;
         Ok(decrypt(cipher, key, None, encrypted_data)?)
     }
 
+    /// 使用指定模式加密数据，自动生成随机IV/随机数。
+    /// Encrypt data with the given mode, generating a random IV/nonce.
+    ///
+    /// # 参数
+    /// * `mode`: 加密模式，GCM 为推荐的认证加密模式。
+    /// * `key`: 密钥，长度必须为16、24或32字节。
+    /// * `data`: 要加密的明文数据。
+    /// * `aad`: 关联数据，仅 GCM 模式支持，参与认证但不加密。
+    /// # 返回
+    /// * 成功时返回自描述的密文信封，否则返回错误。
+    /// # Parameters
+    /// * `mode`: Cipher mode, GCM is the recommended authenticated mode.
+    /// * `key`: Key, length must be 16, 24, or 32 bytes.
+    /// * `data`: Plaintext data to be encrypted.
+    /// * `aad`: Associated data, GCM only; authenticated but not encrypted.
+    /// # Returns
+    /// * Returns the self-describing ciphertext envelope on success, or an error on failure.
+    pub fn encrypt(
+        mode: AesMode,
+        key: &[u8],
+        data: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<AesCiphertext, Box<dyn Error>> {
+        let mut iv = vec![0u8; mode.iv_len()];
+        rand_bytes(&mut iv)?;
+        Self::encrypt_with_iv(mode, key, &iv, data, aad)
+    }
+
+    /// 使用指定模式和调用方提供的IV/随机数加密数据。
+    /// Encrypt data with the given mode and a caller-supplied IV/nonce.
+    ///
+    /// 同一密钥下绝不能重复使用 IV/随机数，尤其是 CTR 和 GCM 模式。
+    /// An IV/nonce must never be reused under the same key, especially for CTR and GCM.
+    ///
+    /// # 参数
+    /// * `mode`: 加密模式。
+    /// * `key`: 密钥，长度必须为16、24或32字节。
+    /// * `iv`: IV/随机数，长度必须等于 `mode.iv_len()`。
+    /// * `data`: 要加密的明文数据。
+    /// * `aad`: 关联数据，仅 GCM 模式支持。
+    /// # 返回
+    /// * 成功时返回自描述的密文信封，否则返回错误。
+    /// # Parameters
+    /// * `mode`: Cipher mode.
+    /// * `key`: Key, length must be 16, 24, or 32 bytes.
+    /// * `iv`: IV/nonce, length must equal `mode.iv_len()`.
+    /// * `data`: Plaintext data to be encrypted.
+    /// * `aad`: Associated data, GCM only.
+    /// # Returns
+    /// * Returns the self-describing ciphertext envelope on success, or an error on failure.
+    pub fn encrypt_with_iv(
+        mode: AesMode,
+        key: &[u8],
+        iv: &[u8],
+        data: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<AesCiphertext, Box<dyn Error>> {
+        let cipher = Self::get_mode_cipher(mode, key)?;
+        Self::check_params(mode, iv, aad)?;
+        let iv_param = if iv.is_empty() { None } else { Some(iv) };
+        let (ciphertext, tag) = if mode.is_authenticated() {
+            let mut tag = vec![0u8; GCM_TAG_LEN];
+            let ciphertext = encrypt_aead(
+                cipher,
+                key,
+                iv_param,
+                aad.unwrap_or_default(),
+                data,
+                &mut tag,
+            )?;
+            (ciphertext, tag)
+        } else {
+            (encrypt(cipher, key, iv_param, data)?, Vec::new())
+        };
+        Ok(AesCiphertext {
+            mode,
+            iv: iv.to_vec(),
+            tag,
+            ciphertext,
+        })
+    }
+
+    /// 解密自描述的密文信封，GCM 模式下校验认证标签。
+    /// Decrypt a self-describing ciphertext envelope, verifying the tag in GCM mode.
+    ///
+    /// # 参数
+    /// * `key`: 密钥，必须与加密时使用的密钥相同。
+    /// * `envelope`: 密文信封。
+    /// * `aad`: 关联数据，必须与加密时提供的相同。
+    /// # 返回
+    /// * 成功时返回解密后的明文字节向量；标签校验失败或参数错误时返回错误。
+    /// # Parameters
+    /// * `key`: Key, must be the same as the one used for encryption.
+    /// * `envelope`: Ciphertext envelope.
+    /// * `aad`: Associated data, must match the data given at encryption time.
+    /// # Returns
+    /// * Returns the decrypted plaintext on success, or an error on tag mismatch or bad input.
+    pub fn decrypt(
+        key: &[u8],
+        envelope: &AesCiphertext,
+        aad: Option<&[u8]>,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mode = envelope.mode;
+        let cipher = Self::get_mode_cipher(mode, key)?;
+        Self::check_params(mode, &envelope.iv, aad)?;
+        let iv_param = if envelope.iv.is_empty() {
+            None
+        } else {
+            Some(envelope.iv.as_slice())
+        };
+        if mode.is_authenticated() {
+            if envelope.tag.len() != GCM_TAG_LEN {
+                return Err("无效的GCM认证标签长度".into());
+            }
+            decrypt_aead(
+                cipher,
+                key,
+                iv_param,
+                aad.unwrap_or_default(),
+                &envelope.ciphertext,
+                &envelope.tag,
+            )
+            .map_err(|_| "GCM认证标签校验失败，密钥、关联数据或密文不匹配".into())
+        } else {
+            Ok(decrypt(cipher, key, iv_param, &envelope.ciphertext)?)
+        }
+    }
+
+    /// 校验IV长度及关联数据是否与模式匹配。
+    /// Check that the IV length and associated data match the mode.
+    fn check_params(mode: AesMode, iv: &[u8], aad: Option<&[u8]>) -> Result<(), Box<dyn Error>> {
+        if iv.len() != mode.iv_len() {
+            return Err(format!("无效的IV长度，{:?}模式必须为{}字节", mode, mode.iv_len()).into());
+        }
+        if aad.is_some() && !mode.is_authenticated() {
+            return Err("关联数据仅支持GCM模式".into());
+        }
+        Ok(())
+    }
+
+    /// 根据模式和密钥长度选择对应的AES密码器。
+    /// Select the appropriate AES cipher based on the mode and key length.
+    ///
+    /// # 参数
+    /// * `mode`: 加密模式
+    /// * `key`: 密钥
+    /// # 返回
+    /// * 对应的AES密码器
+    /// # Parameters
+    /// * `mode`: Cipher mode
+    /// * `key`: Key
+    /// # Returns
+    /// * Corresponding AES cipher
+    fn get_mode_cipher(mode: AesMode, key: &[u8]) -> Result<Cipher, Box<dyn Error>> {
+        let cipher = match (mode, key.len()) {
+            (AesMode::Ecb, _) => return Self::get_aes_cipher(key),
+            (AesMode::Cbc, 16) => Cipher::aes_128_cbc(),
+            (AesMode::Cbc, 24) => Cipher::aes_192_cbc(),
+            (AesMode::Cbc, 32) => Cipher::aes_256_cbc(),
+            (AesMode::Ctr, 16) => Cipher::aes_128_ctr(),
+            (AesMode::Ctr, 24) => Cipher::aes_192_ctr(),
+            (AesMode::Ctr, 32) => Cipher::aes_256_ctr(),
+            (AesMode::Gcm, 16) => Cipher::aes_128_gcm(),
+            (AesMode::Gcm, 24) => Cipher::aes_192_gcm(),
+            (AesMode::Gcm, 32) => Cipher::aes_256_gcm(),
+            _ => return Err("无效的密钥长度，必须为16、24或32字节".into()),
+        };
+        Ok(cipher)
+    }
+
     /// 根据密钥长度选择对应的AES密码器。
     /// Select the appropriate AES cipher based on the key length.
     ///
@@ -61,4 +377,4 @@ impl AESAlgorithm {
             _ => Err("无效的密钥长度，必须为16、24或32字节".into()),
         }
     }
-}
\ No newline at end of file
+}
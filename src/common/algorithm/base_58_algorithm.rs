@@ -1,4 +1,6 @@
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
 use num_bigint::BigUint;
+use thiserror::Error;
 
 /// 定义 Base58 编码所使用的字母表
 const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
@@ -14,6 +16,74 @@ const INDEXES: [i8; 128] = {
     }
     indexes
 };
+/// Base58Check 校验和长度
+const CHECKSUM_LEN: usize = 4;
+/// 地址中公钥哈希或脚本哈希（HASH160）的长度
+const ADDRESS_HASH_LEN: usize = 20;
+
+/// Base58 编解码错误
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Base58Error {
+    #[error("非法的 Base58 字符 / Illegal Base58 character")]
+    IllegalCharacter,
+
+    #[error("数据长度不足 / Input too short")]
+    TooShort,
+
+    #[error("校验和不匹配 / Checksum mismatch: expected {expected:02x?}, found {found:02x?}")]
+    ChecksumMismatch { expected: [u8; 4], found: [u8; 4] },
+
+    #[error("版本字节不匹配 / Version mismatch: expected {expected:#04x}, found {found:#04x}")]
+    VersionMismatch { expected: u8, found: u8 },
+
+    #[error("负载长度错误 / Invalid payload length: expected {expected}, found {found}")]
+    InvalidPayloadLength { expected: usize, found: usize },
+}
+
+/// Base58Check 地址版本字节，区分网络和地址类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base58Version {
+    /// 主网公钥哈希地址（P2PKH），以 "1" 开头
+    Mainnet,
+    /// 测试网公钥哈希地址，以 "m" 或 "n" 开头
+    Testnet,
+    /// 主网脚本哈希地址（P2SH），以 "3" 开头
+    P2sh,
+    /// 测试网脚本哈希地址，以 "2" 开头
+    TestnetP2sh,
+    /// Ed25519 公钥哈希地址，以 "E" 开头，与 secp256k1 地址互不通用
+    Ed25519,
+    /// SM2 公钥摘要地址，以 "S" 开头，与 secp256k1 地址互不通用
+    Sm2,
+}
+
+impl Base58Version {
+    /// 获取版本字节
+    pub fn to_byte(self) -> u8 {
+        match self {
+            Base58Version::Mainnet => 0x00,
+            Base58Version::Testnet => 0x6f,
+            Base58Version::P2sh => 0x05,
+            Base58Version::TestnetP2sh => 0xc4,
+            Base58Version::Ed25519 => 0x21,
+            Base58Version::Sm2 => 0x3f,
+        }
+    }
+
+    /// 从版本字节解析，未知版本返回 `None`
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x00 => Some(Base58Version::Mainnet),
+            0x6f => Some(Base58Version::Testnet),
+            0x05 => Some(Base58Version::P2sh),
+            0xc4 => Some(Base58Version::TestnetP2sh),
+            0x21 => Some(Base58Version::Ed25519),
+            0x3f => Some(Base58Version::Sm2),
+            _ => None,
+        }
+    }
+}
+
 pub struct Base58Algorithm;
 impl Base58Algorithm {
     /// 对输入的字节数组进行 Base58 编码
@@ -100,13 +170,67 @@ impl Base58Algorithm {
         Ok(BigUint::from_bytes_be(&decoded))
     }
 
+    /// 对版本字节和负载进行 Base58Check 编码，末尾附加 4 字节双重 SHA-256 校验和
+    pub fn encode_check(version: u8, payload: &[u8]) -> String {
+        let mut data = Vec::with_capacity(1 + payload.len() + CHECKSUM_LEN);
+        data.push(version);
+        data.extend_from_slice(payload);
+        let checksum = Self::checksum(&data);
+        data.extend_from_slice(&checksum);
+        Self::encode(&data)
+    }
+
+    /// 对 Base58Check 字符串进行解码并校验校验和，返回版本字节和负载
+    pub fn decode_check(input: &str) -> Result<(u8, Vec<u8>), Base58Error> {
+        let decoded = Self::decode(input).map_err(|_| Base58Error::IllegalCharacter)?;
+        if decoded.len() < 1 + CHECKSUM_LEN {
+            return Err(Base58Error::TooShort);
+        }
+        let (data, found) = decoded.split_at(decoded.len() - CHECKSUM_LEN);
+        let expected = Self::checksum(data);
+        if expected != found {
+            let mut found_bytes = [0u8; CHECKSUM_LEN];
+            found_bytes.copy_from_slice(found);
+            return Err(Base58Error::ChecksumMismatch {
+                expected,
+                found: found_bytes,
+            });
+        }
+        Ok((data[0], data[1..].to_vec()))
+    }
+
+    /// 校验地址的校验和、版本字节和长度，成功时返回 20 字节的 HASH160
+    pub fn validate_address(address: &str, version: Base58Version) -> Result<Vec<u8>, Base58Error> {
+        let (found, payload) = Self::decode_check(address)?;
+        if found != version.to_byte() {
+            return Err(Base58Error::VersionMismatch {
+                expected: version.to_byte(),
+                found,
+            });
+        }
+        if payload.len() != ADDRESS_HASH_LEN {
+            return Err(Base58Error::InvalidPayloadLength {
+                expected: ADDRESS_HASH_LEN,
+                found: payload.len(),
+            });
+        }
+        Ok(payload)
+    }
+
+    /// 计算双重 SHA-256 的前 4 字节作为校验和
+    fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+        let hash = BaseAlgorithm::hash_twice(HashAlgorithm::Sha256, data);
+        let mut checksum = [0u8; CHECKSUM_LEN];
+        checksum.copy_from_slice(&hash[..CHECKSUM_LEN]);
+        checksum
+    }
+
     /// 执行除法取模操作，返回余数
     fn divmod(number: &mut [u8], first_digit: usize, base: u32, divisor: u32) -> u8 {
         let mut remainder = 0;
-        for i in first_digit..number.len() {
-            let digit = number[i] as u32;
-            let temp = remainder * base + digit;
-            number[i] = (temp / divisor) as u8;
+        for digit in number.iter_mut().skip(first_digit) {
+            let temp = remainder * base + *digit as u32;
+            *digit = (temp / divisor) as u8;
             remainder = temp % divisor;
         }
         remainder as u8
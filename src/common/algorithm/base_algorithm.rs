@@ -1,70 +1,242 @@
 /// 基础算法工具模块，提供加密相关功能
-use sha2::{Digest, Sha256};
+use blake2::Blake2b512;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use sha3::{Keccak256, Sha3_256};
+use sm3::Sm3;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 哈希算法错误类型
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HashError {
+    #[error("不支持的哈希算法 / Unsupported hash algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+}
+
+/// 支持的哈希算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    /// SHA-256，32 字节
+    Sha256,
+    /// SHA-512，64 字节
+    Sha512,
+    /// SHA3-256 (FIPS 202)，32 字节
+    Sha3_256,
+    /// Keccak-256（以太坊使用的原始填充版本），32 字节
+    Keccak256,
+    /// RIPEMD-160，20 字节
+    Ripemd160,
+    /// BLAKE2b-512，64 字节
+    Blake2b,
+    /// BLAKE3，32 字节
+    Blake3,
+    /// SM3（国密 GB/T 32905），32 字节
+    Sm3,
+}
+
+impl HashAlgorithm {
+    /// 全部支持的哈希算法
+    pub const ALL: [HashAlgorithm; 8] = [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha3_256,
+        HashAlgorithm::Keccak256,
+        HashAlgorithm::Ripemd160,
+        HashAlgorithm::Blake2b,
+        HashAlgorithm::Blake3,
+        HashAlgorithm::Sm3,
+    ];
+
+    /// 获取算法的标准名称，可被 `FromStr` 解析
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha512 => "SHA-512",
+            HashAlgorithm::Sha3_256 => "SHA3-256",
+            HashAlgorithm::Keccak256 => "KECCAK-256",
+            HashAlgorithm::Ripemd160 => "RIPEMD-160",
+            HashAlgorithm::Blake2b => "BLAKE2B-512",
+            HashAlgorithm::Blake3 => "BLAKE3",
+            HashAlgorithm::Sm3 => "SM3",
+        }
+    }
+
+    /// 获取摘要长度（字节）
+    pub fn output_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Ripemd160 => 20,
+            HashAlgorithm::Sha512 | HashAlgorithm::Blake2b => 64,
+            _ => 32,
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = HashError;
+
+    /// 按名称解析哈希算法，忽略大小写，同时接受不带连字符的写法（如 "sha256"）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_ascii_uppercase().replace(['-', '_'], "");
+        match normalized.as_str() {
+            "SHA256" => Ok(HashAlgorithm::Sha256),
+            "SHA512" => Ok(HashAlgorithm::Sha512),
+            "SHA3256" => Ok(HashAlgorithm::Sha3_256),
+            "KECCAK256" => Ok(HashAlgorithm::Keccak256),
+            "RIPEMD160" => Ok(HashAlgorithm::Ripemd160),
+            "BLAKE2B" | "BLAKE2B512" => Ok(HashAlgorithm::Blake2b),
+            "BLAKE3" => Ok(HashAlgorithm::Blake3),
+            "SM3" => Ok(HashAlgorithm::Sm3),
+            _ => Err(HashError::UnsupportedAlgorithm(s.to_string())),
+        }
+    }
+}
+
+/// 各算法的内部哈希状态
+enum HasherState {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Sha3_256(Sha3_256),
+    Keccak256(Keccak256),
+    Ripemd160(Ripemd160),
+    Blake2b(Blake2b512),
+    Blake3(Box<blake3::Hasher>),
+    Sm3(Sm3),
+}
+
+/// 增量哈希器，用于分块处理大块数据（如区块体）
+///
+/// 实现了 `std::io::Write`，可以配合 `std::io::copy` 直接从文件或网络流中读取数据。
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+}
+
+impl Hasher {
+    /// 创建指定算法的增量哈希器
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha3_256 => HasherState::Sha3_256(Sha3_256::default()),
+            HashAlgorithm::Keccak256 => HasherState::Keccak256(Keccak256::default()),
+            HashAlgorithm::Ripemd160 => HasherState::Ripemd160(Ripemd160::new()),
+            HashAlgorithm::Blake2b => HasherState::Blake2b(Blake2b512::default()),
+            HashAlgorithm::Blake3 => HasherState::Blake3(Box::default()),
+            HashAlgorithm::Sm3 => HasherState::Sm3(Sm3::default()),
+        };
+        Hasher { algorithm, state }
+    }
+
+    /// 获取哈希器使用的算法
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// 追加待哈希的数据
+    pub fn update(&mut self, data: &[u8]) -> &mut Self {
+        match &mut self.state {
+            HasherState::Sha256(h) => Digest::update(h, data),
+            HasherState::Sha512(h) => Digest::update(h, data),
+            HasherState::Sha3_256(h) => sha3::Digest::update(h, data),
+            HasherState::Keccak256(h) => sha3::Digest::update(h, data),
+            HasherState::Ripemd160(h) => Digest::update(h, data),
+            HasherState::Blake2b(h) => blake2::Digest::update(h, data),
+            HasherState::Blake3(h) => {
+                h.update(data);
+            }
+            HasherState::Sm3(h) => sm3::Digest::update(h, data),
+        }
+        self
+    }
+
+    /// 完成哈希计算并返回摘要
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HasherState::Sha256(h) => h.finalize().to_vec(),
+            HasherState::Sha512(h) => h.finalize().to_vec(),
+            HasherState::Sha3_256(h) => sha3::Digest::finalize(h).to_vec(),
+            HasherState::Keccak256(h) => sha3::Digest::finalize(h).to_vec(),
+            HasherState::Ripemd160(h) => h.finalize().to_vec(),
+            HasherState::Blake2b(h) => blake2::Digest::finalize(h).to_vec(),
+            HasherState::Blake3(h) => h.finalize().as_bytes().to_vec(),
+            HasherState::Sm3(h) => sm3::Digest::finalize(h).to_vec(),
+        }
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 pub struct BaseAlgorithm;
 impl BaseAlgorithm {
     /// 对输入的数据使用指定的哈希算法进行编码
     ///
     /// # 参数
-    /// - `algorithm`: 哈希算法的名称，目前仅支持 "SHA-256"
+    /// - `algorithm`: 哈希算法的名称，如 "SHA-256"、"KECCAK-256"，参见 `HashAlgorithm::name`
     /// - `data`: 待编码的数据
     ///
     /// # 返回值
-    /// 如果输入数据为 `None`，则返回 `None`；否则返回编码后的字节数组
-    pub fn encode(algorithm: &str, data: Option<&[u8]>) -> Option<Vec<u8>> {
+    /// 算法不受支持时返回错误；如果输入数据为 `None`，则返回 `None`；否则返回编码后的字节数组
+    pub fn encode(algorithm: &str, data: Option<&[u8]>) -> Result<Option<Vec<u8>>, HashError> {
+        // 解析算法名称，不支持的算法直接返回错误
+        let algorithm = algorithm.parse::<HashAlgorithm>()?;
         // 如果输入数据为 None，直接返回 None
-        if data.is_none() {
-            return None;
-        }
-        let data = data.unwrap();
-        // 根据算法名称选择对应的哈希算法
-        match algorithm {
-            "SHA-256" => {
-                // 创建一个新的 Sha256 实例
-                let mut hasher = Sha256::new();
-                // 更新哈希器的输入数据
-                hasher.update(data);
-                // 完成哈希计算并获取结果
-                let result = hasher.finalize();
-                // 将结果转换为 Vec<u8> 类型并返回
-                Some(result.to_vec())
-            }
-            _ => panic!("Unsupported algorithm: {}", algorithm),
-        }
+        Ok(data.map(|data| Self::hash(algorithm, data)))
     }
 
     /// 对输入的数据使用指定的哈希算法进行两次编码
     ///
     /// # 参数
-    /// - `algorithm`: 哈希算法的名称，目前仅支持 "SHA-256"
+    /// - `algorithm`: 哈希算法的名称，如 "SHA-256"，参见 `HashAlgorithm::name`
     /// - `data`: 待编码的数据
     ///
     /// # 返回值
-    /// 如果输入数据为 `None`，则返回 `None`；否则返回两次编码后的字节数组
-    pub fn encode_twice(algorithm: &str, data: Option<&[u8]>) -> Option<Vec<u8>> {
-        // 如果输入数据为 None，直接返回 None
-        if data.is_none() {
-            return None;
-        }
-        let data = data.unwrap();
-        // 根据算法名称选择对应的哈希算法
-        match algorithm {
-            "SHA-256" => {
-                // 第一次编码
-                let first_encoded = {
-                    let mut hasher = Sha256::new();
-                    hasher.update(data);
-                    hasher.finalize()
-                };
-                // 第二次编码
-                let second_encoded = {
-                    let mut hasher = Sha256::new();
-                    hasher.update(&first_encoded);
-                    hasher.finalize()
-                };
-                // 将结果转换为 Vec<u8> 类型并返回
-                Some(second_encoded.to_vec())
-            }
-            _ => panic!("Unsupported algorithm: {}", algorithm),
-        }
+    /// 算法不受支持时返回错误；如果输入数据为 `None`，则返回 `None`；否则返回两次编码后的字节数组
+    pub fn encode_twice(
+        algorithm: &str,
+        data: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, HashError> {
+        let algorithm = algorithm.parse::<HashAlgorithm>()?;
+        Ok(data.map(|data| Self::hash_twice(algorithm, data)))
+    }
+
+    /// 使用指定的哈希算法计算摘要
+    ///
+    /// # 参数
+    /// - `algorithm`: 哈希算法
+    /// - `data`: 待哈希的数据
+    pub fn hash(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+        let mut hasher = Hasher::new(algorithm);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// 使用指定的哈希算法连续计算两次摘要，如比特币的 SHA-256d
+    ///
+    /// # 参数
+    /// - `algorithm`: 哈希算法
+    /// - `data`: 待哈希的数据
+    pub fn hash_twice(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+        // 第一次编码
+        let first_encoded = Self::hash(algorithm, data);
+        // 第二次编码
+        Self::hash(algorithm, &first_encoded)
     }
 }
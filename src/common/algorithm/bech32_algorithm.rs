@@ -0,0 +1,326 @@
+use thiserror::Error;
+
+/// Bech32 编码所使用的字母表
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// 人类可读部分与数据部分之间的分隔符
+const SEPARATOR: char = '1';
+/// 编码结果的最大长度
+const MAX_LENGTH: usize = 90;
+/// 校验和长度（5 位字符个数）
+const CHECKSUM_LEN: usize = 6;
+/// BCH 校验和生成多项式
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+/// Bech32m 校验常量（BIP-350）
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// 比特币主网 SegWit 地址的人类可读部分
+pub const MAINNET_HRP: &str = "bc";
+/// 比特币测试网 SegWit 地址的人类可读部分
+pub const TESTNET_HRP: &str = "tb";
+
+/// Bech32 校验和变体
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bech32Variant {
+    /// BIP-173，用于见证版本 0
+    Bech32,
+    /// BIP-350，用于见证版本 1 到 16
+    Bech32m,
+}
+
+impl Bech32Variant {
+    /// 校验和多项式的目标常量
+    fn constant(self) -> u32 {
+        match self {
+            Bech32Variant::Bech32 => 1,
+            Bech32Variant::Bech32m => BECH32M_CONST,
+        }
+    }
+
+    /// 根据多项式结果识别变体
+    fn from_polymod(polymod: u32) -> Option<Self> {
+        match polymod {
+            1 => Some(Bech32Variant::Bech32),
+            BECH32M_CONST => Some(Bech32Variant::Bech32m),
+            _ => None,
+        }
+    }
+}
+
+/// Bech32 编解码错误
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Bech32Error {
+    #[error("长度无效 / Invalid length: {0}")]
+    InvalidLength(usize),
+
+    #[error("大小写混用 / Mixed case")]
+    MixedCase,
+
+    #[error("缺少分隔符 / Missing separator")]
+    MissingSeparator,
+
+    #[error("人类可读部分无效 / Invalid human-readable part")]
+    InvalidHrp,
+
+    #[error("非法字符 / Invalid character {character:?} at position {position}")]
+    InvalidCharacter { position: usize, character: char },
+
+    #[error("非ASCII字符 / Non-ASCII character {character:?} at position {position}")]
+    NonAsciiCharacter { position: usize, character: char },
+
+    #[error("校验和错误 / Invalid checksum, suspected error positions: {error_positions:?}")]
+    InvalidChecksum { error_positions: Vec<usize> },
+
+    #[error("数据值超出范围 / Data value out of range: {0}")]
+    InvalidData(u8),
+
+    #[error("位填充无效 / Invalid padding")]
+    InvalidPadding,
+
+    #[error("人类可读部分不匹配 / HRP mismatch: expected {expected}, found {found}")]
+    HrpMismatch { expected: String, found: String },
+
+    #[error("见证版本无效 / Invalid witness version: {0}")]
+    InvalidWitnessVersion(u8),
+
+    #[error("见证程序长度无效 / Invalid witness program length: {0}")]
+    InvalidProgramLength(usize),
+
+    #[error("校验和变体与见证版本不符 / Checksum variant does not match witness version")]
+    InvalidVariant,
+}
+
+pub struct Bech32Algorithm;
+impl Bech32Algorithm {
+    /// 对人类可读部分和 5 位数据进行 Bech32/Bech32m 编码
+    pub fn encode(hrp: &str, data: &[u8], variant: Bech32Variant) -> Result<String, Bech32Error> {
+        Self::check_hrp(hrp)?;
+        if let Some(&value) = data.iter().find(|&&value| value >= 32) {
+            return Err(Bech32Error::InvalidData(value));
+        }
+        let hrp = hrp.to_ascii_lowercase();
+        let length = hrp.len() + 1 + data.len() + CHECKSUM_LEN;
+        if length > MAX_LENGTH {
+            return Err(Bech32Error::InvalidLength(length));
+        }
+        let checksum = Self::create_checksum(&hrp, data, variant);
+        let mut encoded = String::with_capacity(length);
+        encoded.push_str(&hrp);
+        encoded.push(SEPARATOR);
+        for &value in data.iter().chain(checksum.iter()) {
+            encoded.push(CHARSET[value as usize] as char);
+        }
+        Ok(encoded)
+    }
+
+    /// 对 Bech32/Bech32m 字符串进行解码，返回人类可读部分、5 位数据和变体
+    ///
+    /// 校验和错误时，`InvalidChecksum` 中给出单字符错误的疑似位置（基于输入字符串的下标）；
+    /// 若无法通过修改单个字符修复，则位置列表为空。
+    pub fn decode(input: &str) -> Result<(String, Vec<u8>, Bech32Variant), Bech32Error> {
+        let (hrp, values) = Self::split(input)?;
+        let polymod = Self::polymod(&Self::checksum_input(&hrp, &values));
+        let variant =
+            Bech32Variant::from_polymod(polymod).ok_or_else(|| Bech32Error::InvalidChecksum {
+                error_positions: Self::locate_errors(&hrp, &values),
+            })?;
+        let data = values[..values.len() - CHECKSUM_LEN].to_vec();
+        Ok((hrp, data, variant))
+    }
+
+    /// 在 8 位和 5 位等不同位宽之间转换数据
+    pub fn convert_bits(
+        data: &[u8],
+        from: u32,
+        to: u32,
+        pad: bool,
+    ) -> Result<Vec<u8>, Bech32Error> {
+        let mut acc: u32 = 0;
+        let mut bits: u32 = 0;
+        let max_value: u32 = (1 << to) - 1;
+        let mut result = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+        for &value in data {
+            if (value as u32) >> from != 0 {
+                return Err(Bech32Error::InvalidData(value));
+            }
+            acc = (acc << from) | value as u32;
+            bits += from;
+            while bits >= to {
+                bits -= to;
+                result.push(((acc >> bits) & max_value) as u8);
+            }
+        }
+        if pad {
+            if bits > 0 {
+                result.push(((acc << (to - bits)) & max_value) as u8);
+            }
+        } else if bits >= from || ((acc << (to - bits)) & max_value) != 0 {
+            return Err(Bech32Error::InvalidPadding);
+        }
+        Ok(result)
+    }
+
+    /// 生成 SegWit 地址，见证版本 0 使用 Bech32，版本 1 到 16 使用 Bech32m
+    pub fn encode_segwit_address(
+        hrp: &str,
+        witness_version: u8,
+        program: &[u8],
+    ) -> Result<String, Bech32Error> {
+        Self::check_witness_program(witness_version, program)?;
+        let variant = if witness_version == 0 {
+            Bech32Variant::Bech32
+        } else {
+            Bech32Variant::Bech32m
+        };
+        let mut data = vec![witness_version];
+        data.extend(Self::convert_bits(program, 8, 5, true)?);
+        Self::encode(hrp, &data, variant)
+    }
+
+    /// 解析 SegWit 地址，返回见证版本和见证程序
+    pub fn decode_segwit_address(hrp: &str, address: &str) -> Result<(u8, Vec<u8>), Bech32Error> {
+        let (found, data, variant) = Self::decode(address)?;
+        if !found.eq_ignore_ascii_case(hrp) {
+            return Err(Bech32Error::HrpMismatch {
+                expected: hrp.to_ascii_lowercase(),
+                found,
+            });
+        }
+        let (&witness_version, program) = data
+            .split_first()
+            .ok_or(Bech32Error::InvalidProgramLength(0))?;
+        let program = Self::convert_bits(program, 5, 8, false)?;
+        Self::check_witness_program(witness_version, &program)?;
+        let expected = if witness_version == 0 {
+            Bech32Variant::Bech32
+        } else {
+            Bech32Variant::Bech32m
+        };
+        if variant != expected {
+            return Err(Bech32Error::InvalidVariant);
+        }
+        Ok((witness_version, program))
+    }
+
+    /// 校验见证版本和见证程序长度
+    fn check_witness_program(witness_version: u8, program: &[u8]) -> Result<(), Bech32Error> {
+        if witness_version > 16 {
+            return Err(Bech32Error::InvalidWitnessVersion(witness_version));
+        }
+        if program.len() < 2 || program.len() > 40 {
+            return Err(Bech32Error::InvalidProgramLength(program.len()));
+        }
+        if witness_version == 0 && program.len() != 20 && program.len() != 32 {
+            return Err(Bech32Error::InvalidProgramLength(program.len()));
+        }
+        Ok(())
+    }
+
+    /// 校验人类可读部分
+    fn check_hrp(hrp: &str) -> Result<(), Bech32Error> {
+        if hrp.is_empty() || hrp.len() > 83 || hrp.bytes().any(|c| !(33..=126).contains(&c)) {
+            return Err(Bech32Error::InvalidHrp);
+        }
+        Ok(())
+    }
+
+    /// 拆分输入字符串，返回小写的人类可读部分和含校验和的 5 位数据
+    ///
+    /// 先拒绝非 ASCII 输入，之后的字节下标即字符位置
+    fn split(input: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+        if let Some((position, character)) = input.chars().enumerate().find(|(_, c)| !c.is_ascii())
+        {
+            return Err(Bech32Error::NonAsciiCharacter {
+                position,
+                character,
+            });
+        }
+        if input.len() > MAX_LENGTH {
+            return Err(Bech32Error::InvalidLength(input.len()));
+        }
+        let has_lower = input.bytes().any(|c| c.is_ascii_lowercase());
+        let has_upper = input.bytes().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper {
+            return Err(Bech32Error::MixedCase);
+        }
+        let input = input.to_ascii_lowercase();
+        let separator = input
+            .rfind(SEPARATOR)
+            .ok_or(Bech32Error::MissingSeparator)?;
+        let hrp = &input[..separator];
+        Self::check_hrp(hrp)?;
+        let data_part = &input[separator + 1..];
+        if data_part.len() < CHECKSUM_LEN {
+            return Err(Bech32Error::InvalidLength(input.len()));
+        }
+        let mut values = Vec::with_capacity(data_part.len());
+        for (i, c) in data_part.bytes().enumerate() {
+            let value =
+                CHARSET
+                    .iter()
+                    .position(|&x| x == c)
+                    .ok_or(Bech32Error::InvalidCharacter {
+                        position: separator + 1 + i,
+                        character: c as char,
+                    })?;
+            values.push(value as u8);
+        }
+        Ok((hrp.to_string(), values))
+    }
+
+    /// 逐个替换数据部分的字符，找出能使校验和（任一变体）成立的位置
+    fn locate_errors(hrp: &str, values: &[u8]) -> Vec<usize> {
+        let offset = hrp.len() + 1;
+        let mut candidate = Self::checksum_input(hrp, values);
+        let data_start = candidate.len() - values.len();
+        let mut positions = Vec::new();
+        for i in 0..values.len() {
+            let original = candidate[data_start + i];
+            let fixable = (0..32u8).filter(|&v| v != original).any(|v| {
+                candidate[data_start + i] = v;
+                Bech32Variant::from_polymod(Self::polymod(&candidate)).is_some()
+            });
+            candidate[data_start + i] = original;
+            if fixable {
+                positions.push(offset + i);
+            }
+        }
+        positions
+    }
+
+    /// 计算校验和
+    fn create_checksum(hrp: &str, data: &[u8], variant: Bech32Variant) -> [u8; CHECKSUM_LEN] {
+        let mut values = Self::checksum_input(hrp, data);
+        values.extend_from_slice(&[0u8; CHECKSUM_LEN]);
+        let polymod = Self::polymod(&values) ^ variant.constant();
+        let mut checksum = [0u8; CHECKSUM_LEN];
+        for (i, value) in checksum.iter_mut().enumerate() {
+            *value = ((polymod >> (5 * (5 - i))) & 31) as u8;
+        }
+        checksum
+    }
+
+    /// 拼接扩展后的人类可读部分和数据，作为多项式计算的输入
+    fn checksum_input(hrp: &str, data: &[u8]) -> Vec<u8> {
+        let mut values = Vec::with_capacity(hrp.len() * 2 + 1 + data.len());
+        values.extend(hrp.bytes().map(|c| c >> 5));
+        values.push(0);
+        values.extend(hrp.bytes().map(|c| c & 31));
+        values.extend_from_slice(data);
+        values
+    }
+
+    /// BCH 多项式取模
+    fn polymod(values: &[u8]) -> u32 {
+        let mut chk: u32 = 1;
+        for &value in values {
+            let top = chk >> 25;
+            chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+            for (i, generator) in GENERATOR.iter().enumerate() {
+                if (top >> i) & 1 == 1 {
+                    chk ^= generator;
+                }
+            }
+        }
+        chk
+    }
+}
@@ -0,0 +1,601 @@
+/*
+ * BIP-32 分层确定性密钥派生 / BIP-32 hierarchical deterministic key derivation
+ *
+ * 主要功能 / Main functionalities:
+ * 1. 从种子生成主扩展私钥 / Generate the master extended private key from a seed
+ * 2. 强化与普通子密钥派生 / Hardened and normal child key derivation
+ * 3. xprv/xpub (tprv/tpub) Base58Check 序列化 / xprv/xpub (tprv/tpub) Base58Check serialisation
+ * 4. 派生路径解析，如 m/44'/0'/0'/0/5 / Derivation path parsing such as m/44'/0'/0'/0/5
+ *
+ * 派生出的私钥和公钥沿用 `ECDSAAlgorithm` 的BASE64约定，可直接用于签名和地址生成。
+ * 只持有 xpub 即可派生任意数量的普通子公钥，无需为每个收款地址保存私钥。
+ * Derived private and public keys follow the `ECDSAAlgorithm` BASE64 conventions and can be
+ * used directly for signing and address generation. An xpub alone derives any number of
+ * normal child public keys, so no private key has to be stored per receive address.
+ */
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Error};
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use crate::common::algorithm::ecdsa_algorithm::{ECDSAAlgorithm, EcdsaError};
+use base64::{engine::general_purpose, Engine as _};
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey,
+};
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 强化派生索引的起始值 / First index of hardened derivation
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+/// 主密钥 HMAC 的密钥 / HMAC key for the master key
+const MASTER_HMAC_KEY: &[u8] = b"Bitcoin seed";
+/// 序列化后的扩展密钥长度 / Length of a serialised extended key
+const EXTENDED_KEY_LEN: usize = 78;
+/// 种子长度范围 / Seed length range
+const MIN_SEED_LEN: usize = 16;
+const MAX_SEED_LEN: usize = 64;
+
+/// BIP-32 错误类型 / BIP-32 error type
+#[derive(Debug, Error)]
+pub enum Bip32Error {
+    #[error("种子长度必须为16到64字节 / Seed must be 16 to 64 bytes, got {0}")]
+    InvalidSeedLength(usize),
+
+    #[error("派生路径无效 / Invalid derivation path: {0}")]
+    InvalidPath(String),
+
+    #[error("扩展密钥无效 / Invalid extended key: {0}")]
+    InvalidExtendedKey(String),
+
+    #[error("Base58Check解码失败 / Base58Check decode failed: {0}")]
+    Base58(#[from] Base58Error),
+
+    #[error("公钥无法进行强化派生 / Cannot derive a hardened child from a public key")]
+    HardenedFromPublic,
+
+    #[error("子密钥无效，请使用下一个索引 / Invalid child key at index {0}, use the next index")]
+    InvalidChildKey(u32),
+
+    #[error("派生深度超过255 / Derivation depth exceeds 255")]
+    DepthOverflow,
+
+    #[error("HMAC计算失败 / HMAC failed: {0}")]
+    Hmac(#[from] openssl::error::ErrorStack),
+
+    #[error("地址生成错误 / Address generation error: {0}")]
+    Address(#[from] EcdsaError),
+}
+
+/// 扩展密钥所属网络 / Network of an extended key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bip32Network {
+    /// xprv / xpub
+    Mainnet,
+    /// tprv / tpub
+    Testnet,
+}
+
+impl Bip32Network {
+    /// 扩展私钥的版本字节 / Version bytes of an extended private key
+    pub fn private_version(self) -> [u8; 4] {
+        match self {
+            Bip32Network::Mainnet => [0x04, 0x88, 0xad, 0xe4],
+            Bip32Network::Testnet => [0x04, 0x35, 0x83, 0x94],
+        }
+    }
+
+    /// 扩展公钥的版本字节 / Version bytes of an extended public key
+    pub fn public_version(self) -> [u8; 4] {
+        match self {
+            Bip32Network::Mainnet => [0x04, 0x88, 0xb2, 0x1e],
+            Bip32Network::Testnet => [0x04, 0x35, 0x87, 0xcf],
+        }
+    }
+
+    /// 根据版本字节识别网络及是否为私钥 / Identify the network and key kind from version bytes
+    fn from_version(version: &[u8]) -> Option<(Self, bool)> {
+        [Bip32Network::Mainnet, Bip32Network::Testnet]
+            .into_iter()
+            .find_map(|network| {
+                if version == network.private_version() {
+                    Some((network, true))
+                } else if version == network.public_version() {
+                    Some((network, false))
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// 派生路径，如 m/44'/0'/0'/0/5 / Derivation path such as m/44'/0'/0'/0/5
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// 由子索引列表创建路径，强化索引需加上 `HARDENED_OFFSET`
+    /// Create a path from child indices; hardened indices include `HARDENED_OFFSET`
+    pub fn new(indices: Vec<u32>) -> Self {
+        DerivationPath(indices)
+    }
+
+    /// 获取子索引列表 / Get the child indices
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// 在路径末尾追加一个子索引 / Append a child index to the path
+    pub fn child(&self, index: u32) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(index);
+        DerivationPath(indices)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Bip32Error;
+
+    /// 解析 "m/44'/0'/0'/0/5"，强化索引可用 `'`、`h` 或 `H` 标记
+    /// Parse "m/44'/0'/0'/0/5"; hardened indices are marked with `'`, `h` or `H`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+        if parts.next() != Some("m") {
+            return Err(Bip32Error::InvalidPath(s.to_string()));
+        }
+        let mut indices = Vec::new();
+        for part in parts {
+            let (number, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                Some(number) => (number, true),
+                None => (part, false),
+            };
+            if number.is_empty() || !number.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(Bip32Error::InvalidPath(s.to_string()));
+            }
+            let index = number
+                .parse::<u32>()
+                .ok()
+                .filter(|&index| index < HARDENED_OFFSET)
+                .ok_or_else(|| Bip32Error::InvalidPath(s.to_string()))?;
+            indices.push(if hardened {
+                index + HARDENED_OFFSET
+            } else {
+                index
+            });
+        }
+        Ok(DerivationPath(indices))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for &index in &self.0 {
+            if index >= HARDENED_OFFSET {
+                write!(f, "/{}'", index - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 扩展私钥 / Extended private key
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    network: Bip32Network,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: [u8; 32],
+    secret_key: SecretKey,
+}
+
+/// 扩展公钥 / Extended public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    network: Bip32Network,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: [u8; 32],
+    public_key: PublicKey,
+}
+
+impl ExtendedPrivateKey {
+    /// 从种子生成主扩展私钥 / Generate the master extended private key from a seed
+    ///
+    /// 参数 / Parameters:
+    /// - seed: 16到64字节的种子 / 16 to 64 byte seed
+    /// - network: 所属网络 / Network
+    pub fn from_seed(seed: &[u8], network: Bip32Network) -> Result<Self, Bip32Error> {
+        if seed.len() < MIN_SEED_LEN || seed.len() > MAX_SEED_LEN {
+            return Err(Bip32Error::InvalidSeedLength(seed.len()));
+        }
+        let (key, chain_code) = hmac_sha512(MASTER_HMAC_KEY, seed)?;
+        let secret_key = SecretKey::from_be_bytes(&key).map_err(|_| {
+            Bip32Error::InvalidExtendedKey("主私钥无效 / invalid master key".to_string())
+        })?;
+        Ok(ExtendedPrivateKey {
+            network,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+            chain_code,
+            secret_key,
+        })
+    }
+
+    /// 派生子扩展私钥，索引不小于 `HARDENED_OFFSET` 时为强化派生
+    /// Derive a child extended private key; indices from `HARDENED_OFFSET` up are hardened
+    pub fn derive_child(&self, index: u32) -> Result<Self, Bip32Error> {
+        let depth = self.depth.checked_add(1).ok_or(Bip32Error::DepthOverflow)?;
+        let mut data = Vec::with_capacity(37);
+        if index >= HARDENED_OFFSET {
+            data.push(0);
+            data.extend_from_slice(&self.secret_key.to_be_bytes());
+        } else {
+            data.extend_from_slice(&compressed(&self.secret_key.public_key()));
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let (il, chain_code) = hmac_sha512(&self.chain_code, &data)?;
+        let il = parse_scalar(&il).ok_or(Bip32Error::InvalidChildKey(index))?;
+        let child = il + self.secret_key.to_nonzero_scalar().as_ref();
+        let child = Option::<NonZeroScalar>::from(NonZeroScalar::new(child))
+            .ok_or(Bip32Error::InvalidChildKey(index))?;
+
+        Ok(ExtendedPrivateKey {
+            network: self.network,
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            secret_key: SecretKey::from(child),
+        })
+    }
+
+    /// 按路径派生扩展私钥 / Derive an extended private key along a path
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Bip32Error> {
+        path.indices()
+            .iter()
+            .try_fold(self.clone(), |key, &index| key.derive_child(index))
+    }
+
+    /// 获取对应的扩展公钥 / Get the matching extended public key
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            network: self.network,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            public_key: self.secret_key.public_key(),
+        }
+    }
+
+    /// 获取BASE64编码的私钥，可直接用于 `ECDSAAlgorithm::sign`
+    /// Get the BASE64 encoded private key, usable with `ECDSAAlgorithm::sign`
+    pub fn private_key(&self) -> String {
+        general_purpose::STANDARD.encode(self.secret_key.to_be_bytes())
+    }
+
+    /// 获取BASE64编码的压缩公钥 / Get the BASE64 encoded compressed public key
+    pub fn public_key(&self) -> String {
+        general_purpose::STANDARD.encode(compressed(&self.secret_key.public_key()))
+    }
+
+    /// 公钥 HASH160 的前4字节 / First 4 bytes of the public key HASH160
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.secret_key.public_key())
+    }
+
+    /// 所属网络 / Network
+    pub fn network(&self) -> Bip32Network {
+        self.network
+    }
+
+    /// 派生深度，主密钥为0 / Derivation depth, 0 for the master key
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// 父密钥指纹 / Parent key fingerprint
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// 子索引 / Child index
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// 链码 / Chain code
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    /// 不输出私钥 / Never prints the private key
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("network", &self.network)
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &self.parent_fingerprint)
+            .field("child_number", &self.child_number)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ExtendedPrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.network == other.network
+            && self.depth == other.depth
+            && self.parent_fingerprint == other.parent_fingerprint
+            && self.child_number == other.child_number
+            && self.chain_code == other.chain_code
+            && self.secret_key == other.secret_key
+    }
+}
+
+impl Eq for ExtendedPrivateKey {}
+
+impl fmt::Display for ExtendedPrivateKey {
+    /// 序列化为 xprv/tprv / Serialise as xprv/tprv
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut key = [0u8; 33];
+        key[1..].copy_from_slice(&self.secret_key.to_be_bytes());
+        f.write_str(&serialize(
+            self.network.private_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &key,
+        ))
+    }
+}
+
+impl FromStr for ExtendedPrivateKey {
+    type Err = Bip32Error;
+
+    /// 解析 xprv/tprv / Parse xprv/tprv
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = deserialize(s)?;
+        if !raw.private {
+            return Err(Bip32Error::InvalidExtendedKey(
+                "不是扩展私钥 / not an extended private key".to_string(),
+            ));
+        }
+        if raw.key[0] != 0 {
+            return Err(Bip32Error::InvalidExtendedKey(
+                "私钥前缀错误 / bad private key prefix".to_string(),
+            ));
+        }
+        let secret_key = SecretKey::from_be_bytes(&raw.key[1..]).map_err(|_| {
+            Bip32Error::InvalidExtendedKey("私钥无效 / invalid private key".to_string())
+        })?;
+        Ok(ExtendedPrivateKey {
+            network: raw.network,
+            depth: raw.depth,
+            parent_fingerprint: raw.parent_fingerprint,
+            child_number: raw.child_number,
+            chain_code: raw.chain_code,
+            secret_key,
+        })
+    }
+}
+
+impl ExtendedPublicKey {
+    /// 派生普通子扩展公钥，强化索引返回 `HardenedFromPublic`
+    /// Derive a normal child extended public key; hardened indices return `HardenedFromPublic`
+    pub fn derive_child(&self, index: u32) -> Result<Self, Bip32Error> {
+        if index >= HARDENED_OFFSET {
+            return Err(Bip32Error::HardenedFromPublic);
+        }
+        let depth = self.depth.checked_add(1).ok_or(Bip32Error::DepthOverflow)?;
+        let mut data = compressed(&self.public_key).to_vec();
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let (il, chain_code) = hmac_sha512(&self.chain_code, &data)?;
+        let il = parse_scalar(&il).ok_or(Bip32Error::InvalidChildKey(index))?;
+        let point = ProjectivePoint::GENERATOR * il + self.public_key.to_projective();
+        let public_key = PublicKey::from_affine(point.to_affine())
+            .map_err(|_| Bip32Error::InvalidChildKey(index))?;
+
+        Ok(ExtendedPublicKey {
+            network: self.network,
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            public_key,
+        })
+    }
+
+    /// 按路径派生扩展公钥，路径中只能包含普通索引
+    /// Derive an extended public key along a path of normal indices only
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Bip32Error> {
+        path.indices()
+            .iter()
+            .try_fold(self.clone(), |key, &index| key.derive_child(index))
+    }
+
+    /// 获取BASE64编码的压缩公钥 / Get the BASE64 encoded compressed public key
+    pub fn public_key(&self) -> String {
+        general_purpose::STANDARD.encode(compressed(&self.public_key))
+    }
+
+    /// 生成主网 P2PKH 地址 / Generate the mainnet P2PKH address
+    pub fn address(&self) -> Result<String, Bip32Error> {
+        Ok(ECDSAAlgorithm::get_address(&self.public_key())?)
+    }
+
+    /// 公钥 HASH160 的前4字节 / First 4 bytes of the public key HASH160
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+
+    /// 所属网络 / Network
+    pub fn network(&self) -> Bip32Network {
+        self.network
+    }
+
+    /// 派生深度，主密钥为0 / Derivation depth, 0 for the master key
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// 父密钥指纹 / Parent key fingerprint
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// 子索引 / Child index
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// 链码 / Chain code
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    /// 序列化为 xpub/tpub / Serialise as xpub/tpub
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serialize(
+            self.network.public_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &compressed(&self.public_key),
+        ))
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = Bip32Error;
+
+    /// 解析 xpub/tpub / Parse xpub/tpub
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = deserialize(s)?;
+        if raw.private {
+            return Err(Bip32Error::InvalidExtendedKey(
+                "不是扩展公钥 / not an extended public key".to_string(),
+            ));
+        }
+        let public_key = PublicKey::from_sec1_bytes(&raw.key).map_err(|_| {
+            Bip32Error::InvalidExtendedKey("公钥无效 / invalid public key".to_string())
+        })?;
+        Ok(ExtendedPublicKey {
+            network: raw.network,
+            depth: raw.depth,
+            parent_fingerprint: raw.parent_fingerprint,
+            child_number: raw.child_number,
+            chain_code: raw.chain_code,
+            public_key,
+        })
+    }
+}
+
+/// 反序列化后的扩展密钥字段 / Fields of a deserialised extended key
+struct RawExtendedKey {
+    network: Bip32Network,
+    private: bool,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: [u8; 32],
+    key: [u8; 33],
+}
+
+/// 按 BIP-32 格式序列化扩展密钥 / Serialise an extended key in the BIP-32 format
+///
+/// version(4) || depth(1) || fingerprint(4) || child number(4) || chain code(32) || key(33)
+fn serialize(
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: &[u8; 32],
+    key: &[u8; 33],
+) -> String {
+    let mut data = Vec::with_capacity(EXTENDED_KEY_LEN);
+    data.extend_from_slice(&version);
+    data.push(depth);
+    data.extend_from_slice(&parent_fingerprint);
+    data.extend_from_slice(&child_number.to_be_bytes());
+    data.extend_from_slice(chain_code);
+    data.extend_from_slice(key);
+    // Base58Check 的版本字节即扩展密钥版本的首字节 / The Base58Check version byte is the first version byte
+    Base58Algorithm::encode_check(data[0], &data[1..])
+}
+
+/// 解析 Base58Check 编码的扩展密钥 / Parse a Base58Check encoded extended key
+fn deserialize(s: &str) -> Result<RawExtendedKey, Bip32Error> {
+    let (first, rest) = Base58Algorithm::decode_check(s)?;
+    let mut data = vec![first];
+    data.extend_from_slice(&rest);
+    if data.len() != EXTENDED_KEY_LEN {
+        return Err(Bip32Error::InvalidExtendedKey(format!(
+            "长度错误 / invalid length: {}",
+            data.len()
+        )));
+    }
+    let (network, private) = Bip32Network::from_version(&data[..4]).ok_or_else(|| {
+        Bip32Error::InvalidExtendedKey("未知的版本字节 / unknown version bytes".to_string())
+    })?;
+    let depth = data[4];
+    let parent_fingerprint: [u8; 4] = data[5..9].try_into().unwrap();
+    let child_number = u32::from_be_bytes(data[9..13].try_into().unwrap());
+    if depth == 0 && (parent_fingerprint != [0; 4] || child_number != 0) {
+        return Err(Bip32Error::InvalidExtendedKey(
+            "主密钥的父指纹和子索引必须为0 / master key must have zero fingerprint and index"
+                .to_string(),
+        ));
+    }
+    Ok(RawExtendedKey {
+        network,
+        private,
+        depth,
+        parent_fingerprint,
+        child_number,
+        chain_code: data[13..45].try_into().unwrap(),
+        key: data[45..78].try_into().unwrap(),
+    })
+}
+
+/// 计算 HMAC-SHA512 并拆分为左右两半 / Compute HMAC-SHA512 and split it into halves
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<([u8; 32], [u8; 32]), Bip32Error> {
+    let pkey = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha512(), &pkey)?;
+    signer.update(data)?;
+    let mac = signer.sign_to_vec()?;
+    Ok((mac[..32].try_into().unwrap(), mac[32..].try_into().unwrap()))
+}
+
+/// 将32字节解析为小于曲线阶的标量 / Parse 32 bytes as a scalar below the curve order
+fn parse_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    Scalar::from_repr(*FieldBytes::from_slice(bytes)).into()
+}
+
+/// 33字节压缩公钥 / 33-byte compressed public key
+fn compressed(public_key: &PublicKey) -> [u8; 33] {
+    public_key
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .unwrap()
+}
+
+/// 公钥 HASH160 的前4字节 / First 4 bytes of the public key HASH160
+fn fingerprint(public_key: &PublicKey) -> [u8; 4] {
+    let sha = BaseAlgorithm::hash(HashAlgorithm::Sha256, &compressed(public_key));
+    let hash = BaseAlgorithm::hash(HashAlgorithm::Ripemd160, &sha);
+    hash[..4].try_into().unwrap()
+}
@@ -0,0 +1,137 @@
+use crate::common::exception::error_enum::ErrorNum;
+use openssl::symm::{decrypt, encrypt, Cipher};
+use std::error::Error;
+
+/// DES 分组长度（字节），CBC 模式的 IV 长度与之相同
+/// DES block length in bytes, which is also the CBC IV length
+const BLOCK_LEN: usize = 8;
+
+/// 3DES (DES-EDE3) 加密/解密算法工具类
+/// 3DES (DES-EDE3) encryption/decryption algorithm utility class
+///
+/// 仅用于与遗留系统对接，新数据请使用 `AESAlgorithm`。所有错误均以 `ErrorNum::Des3EncryptError` 返回。
+/// Only intended for talking to legacy systems; use `AESAlgorithm` for new data. All errors are
+/// returned as `ErrorNum::Des3EncryptError`.
+pub struct DES3Algorithm;
+
+impl DES3Algorithm {
+    /// 使用3DES ECB模式加密数据，使用PKCS7填充。
+    /// Encrypt data using 3DES ECB mode with PKCS7 padding.
+    ///
+    /// # 参数
+    /// * `key`: 密钥，长度必须为16字节（双密钥 K1K2K1）或24字节（三密钥 K1K2K3）。
+    /// * `data`: 要加密的明文数据。
+    /// # 返回
+    /// * 成功时返回加密后的密文字节向量，否则返回错误。
+    /// # Parameters
+    /// * `key`: Key, length must be 16 bytes (two-key K1K2K1) or 24 bytes (three-key K1K2K3).
+    /// * `data`: Plaintext data to be encrypted.
+    /// # Returns
+    /// * Returns the encrypted ciphertext byte vector on success, or an error on failure.
+    pub fn encode(key: &[u8], data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = Self::expand_key(key)?;
+        encrypt(Cipher::des_ede3(), &key, None, data).map_err(Self::map_error)
+    }
+
+    /// 使用3DES ECB模式解密数据，处理PKCS7填充。
+    /// Decrypt data using 3DES ECB mode, handling PKCS7 padding.
+    ///
+    /// # 参数
+    /// * `key`: 密钥，必须与加密时使用的密钥相同。
+    /// * `encrypted_data`: 要解密的密文字节向量。
+    /// # 返回
+    /// * 成功时返回解密后的明文字节向量，否则返回错误。
+    /// # Parameters
+    /// * `key`: Key, must be the same as the one used for encryption.
+    /// * `encrypted_data`: Ciphertext byte vector to be decrypted.
+    /// # Returns
+    /// * Returns the decrypted plaintext byte vector on success, or an error on failure.
+    pub fn decode(key: &[u8], encrypted_data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = Self::expand_key(key)?;
+        decrypt(Cipher::des_ede3(), &key, None, encrypted_data).map_err(Self::map_error)
+    }
+
+    /// 使用3DES CBC模式加密数据，使用PKCS7填充。
+    /// Encrypt data using 3DES CBC mode with PKCS7 padding.
+    ///
+    /// # 参数
+    /// * `key`: 密钥，长度必须为16或24字节。
+    /// * `iv`: 初始向量，长度必须为8字节。
+    /// * `data`: 要加密的明文数据。
+    /// # 返回
+    /// * 成功时返回加密后的密文字节向量，否则返回错误。
+    /// # Parameters
+    /// * `key`: Key, length must be 16 or 24 bytes.
+    /// * `iv`: Initialisation vector, length must be 8 bytes.
+    /// * `data`: Plaintext data to be encrypted.
+    /// # Returns
+    /// * Returns the encrypted ciphertext byte vector on success, or an error on failure.
+    pub fn encode_cbc(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = Self::expand_key(key)?;
+        Self::check_iv(iv)?;
+        encrypt(Cipher::des_ede3_cbc(), &key, Some(iv), data).map_err(Self::map_error)
+    }
+
+    /// 使用3DES CBC模式解密数据，处理PKCS7填充。
+    /// Decrypt data using 3DES CBC mode, handling PKCS7 padding.
+    ///
+    /// # 参数
+    /// * `key`: 密钥，必须与加密时使用的密钥相同。
+    /// * `iv`: 初始向量，必须与加密时使用的相同。
+    /// * `encrypted_data`: 要解密的密文字节向量。
+    /// # 返回
+    /// * 成功时返回解密后的明文字节向量，否则返回错误。
+    /// # Parameters
+    /// * `key`: Key, must be the same as the one used for encryption.
+    /// * `iv`: Initialisation vector, must be the same as the one used for encryption.
+    /// * `encrypted_data`: Ciphertext byte vector to be decrypted.
+    /// # Returns
+    /// * Returns the decrypted plaintext byte vector on success, or an error on failure.
+    pub fn decode_cbc(
+        key: &[u8],
+        iv: &[u8],
+        encrypted_data: &[u8],
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = Self::expand_key(key)?;
+        Self::check_iv(iv)?;
+        decrypt(Cipher::des_ede3_cbc(), &key, Some(iv), encrypted_data).map_err(Self::map_error)
+    }
+
+    /// 校验密钥长度并将双密钥扩展为 K1K2K1 形式的24字节密钥。
+    /// Validate the key length and expand a two-key variant into a 24-byte K1K2K1 key.
+    ///
+    /// # 参数
+    /// * `key`: 密钥
+    /// # 返回
+    /// * 24字节的三密钥
+    /// # Parameters
+    /// * `key`: Key
+    /// # Returns
+    /// * 24-byte three-key variant
+    fn expand_key(key: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        match key.len() {
+            16 => {
+                let mut expanded = key.to_vec();
+                expanded.extend_from_slice(&key[..BLOCK_LEN]);
+                Ok(expanded)
+            }
+            24 => Ok(key.to_vec()),
+            _ => Err(Box::new(ErrorNum::Des3EncryptError)),
+        }
+    }
+
+    /// 校验 CBC 模式的 IV 长度
+    /// Check the IV length for CBC mode
+    fn check_iv(iv: &[u8]) -> Result<(), Box<dyn Error>> {
+        if iv.len() != BLOCK_LEN {
+            return Err(Box::new(ErrorNum::Des3EncryptError));
+        }
+        Ok(())
+    }
+
+    /// 将底层错误统一映射为 `Des3EncryptError`
+    /// Map underlying errors to `Des3EncryptError`
+    fn map_error<E>(_: E) -> Box<dyn Error> {
+        Box::new(ErrorNum::Des3EncryptError)
+    }
+}
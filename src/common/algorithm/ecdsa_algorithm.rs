@@ -6,19 +6,45 @@
  * 2. 支持ECDSA签名与验证 / Support ECDSA signing and verification
  * 3. 比特币风格地址生成 / Bitcoin-style address generation
  * 4. DER格式签名编解码 / DER format signature encoding/decoding
+ * 5. SegWit (P2WPKH) 地址生成 / SegWit (P2WPKH) address generation
+ * 6. 可恢复签名与公钥恢复 / Recoverable signatures and public key recovery
+ * 7. 对预先计算的哈希签名与验证 / Signing and verifying pre-computed hashes
+ * 8. WIF 私钥导入导出 / WIF private key import and export
+ * 9. ECDH 共享密钥 / ECDH shared secrets
+ *
+ * 字符串函数是 `ecdsa_types` 中强类型密钥和签名的薄封装。
+ * The string functions are thin wrappers over the typed keys and signatures in `ecdsa_types`.
+ *
+ * 所有签名均按 RFC 6979 确定性生成随机数 k，相同私钥和消息总是得到相同签名。
+ * All signatures derive the nonce k deterministically per RFC 6979, so the same private key
+ * and message always yield the same signature.
  */
-use crate::common::algorithm::base_58_algorithm::Base58Algorithm;
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use crate::common::algorithm::bech32_algorithm::{Bech32Algorithm, MAINNET_HRP, TESTNET_HRP};
+use crate::common::algorithm::bip32_algorithm::{Bip32Network, ExtendedPrivateKey};
+use crate::common::algorithm::ecdsa_types::{PrivateKey, PublicKey};
+use crate::common::algorithm::signature_scheme::SignatureScheme;
 use base64::{engine::general_purpose, Engine as _};
 use k256::{
-    ecdsa::{signature::Signer, signature::Verifier, Signature, SigningKey, VerifyingKey},
+    ecdsa::{
+        recoverable,
+        signature::hazmat::{PrehashSigner, PrehashVerifier},
+        Signature, SigningKey, VerifyingKey,
+    },
     elliptic_curve::sec1::{EncodedPoint, ToEncodedPoint},
     Secp256k1,
 };
-use rand::rngs::OsRng;
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
+/// 可恢复签名长度：r(32) || s(32) || v(1) / Recoverable signature length: r(32) || s(32) || v(1)
+pub const RECOVERABLE_SIGNATURE_LEN: usize = 65;
+/// 消息哈希长度 / Message hash length
+const MESSAGE_HASH_LEN: usize = 32;
+/// WIF 中表示压缩公钥的后缀字节 / WIF suffix byte marking a compressed public key
+pub const WIF_COMPRESSED_FLAG: u8 = 0x01;
+
 /// 自定义错误类型 / Custom error type
 #[derive(Debug, Error)]
 pub enum EcdsaError {
@@ -33,13 +59,137 @@ pub enum EcdsaError {
 
     #[error("地址生成错误 / Address generation error: {0}")]
     AddressError(String),
+
+    #[error("签名失败 / Signing failed")]
+    SigningError,
+
+    #[error("消息哈希长度必须为32字节 / Message hash must be 32 bytes, got {0}")]
+    InvalidHashLength(usize),
+
+    #[error("签名格式错误 / Invalid signature encoding")]
+    InvalidSignature,
+
+    #[error("公钥恢复失败 / Public key recovery failed")]
+    RecoveryFailed,
+
+    #[error("DER签名长度错误 / Bad DER signature length")]
+    DerInvalidLength,
+
+    #[error("DER序列头错误 / Bad DER sequence header")]
+    DerInvalidSequence,
+
+    #[error("DER中R长度越界 / DER R length overflows the signature")]
+    DerROverflow,
+
+    #[error("DER长度不一致 / DER lengths do not add up")]
+    DerLengthMismatch,
+
+    #[error("DER整数头错误 / Bad DER integer header")]
+    DerInvalidInteger,
+
+    #[error("DER整数为负 / Negative DER integer")]
+    DerNegativeInteger,
+
+    #[error("DER整数有多余的前导零 / Excess leading zero in DER integer")]
+    DerExcessLeadingZero,
+
+    #[error("签名S值过高，存在延展性 / High-S signature is malleable")]
+    HighS,
+
+    #[error("十六进制解码失败 / Hex decode failed")]
+    HexError,
+
+    #[error("WIF私钥格式错误 / Invalid WIF private key: {0}")]
+    InvalidWif(String),
 }
+
+/// 解析后的地址 / Parsed address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressKind {
+    /// Base58Check 地址（P2PKH/P2SH） / Base58Check address (P2PKH/P2SH)
+    Base58 {
+        version: Base58Version,
+        hash: Vec<u8>,
+    },
+    /// SegWit 地址 / SegWit address
+    SegWit {
+        hrp: String,
+        witness_version: u8,
+        program: Vec<u8>,
+    },
+}
+
+/// WIF 私钥所属网络 / Network of a WIF private key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WifNetwork {
+    /// 主网，前缀 0x80，以 "5"、"K" 或 "L" 开头 / Mainnet, prefix 0x80, starts with "5", "K" or "L"
+    Mainnet,
+    /// 测试网，前缀 0xef，以 "9" 或 "c" 开头 / Testnet, prefix 0xef, starts with "9" or "c"
+    Testnet,
+}
+
+impl WifNetwork {
+    /// 获取前缀字节 / Get the prefix byte
+    pub fn to_byte(self) -> u8 {
+        match self {
+            WifNetwork::Mainnet => 0x80,
+            WifNetwork::Testnet => 0xef,
+        }
+    }
+
+    /// 从前缀字节解析，未知前缀返回 `None` / Parse a prefix byte, `None` if unknown
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x80 => Some(WifNetwork::Mainnet),
+            0xef => Some(WifNetwork::Testnet),
+            _ => None,
+        }
+    }
+}
+
 pub struct ECDSAAlgorithm;
 impl ECDSAAlgorithm {
     /// 生成BASE64编码的私钥 / Generate BASE64 encoded private key
     pub fn generate_private_key() -> String {
-        let signing_key = SigningKey::random(&mut OsRng);
-        general_purpose::STANDARD.encode(signing_key.to_bytes())
+        PrivateKey::generate().to_base64()
+    }
+
+    /// 从种子确定性生成BASE64编码的私钥 / Deterministically generate a BASE64 private key from a seed
+    ///
+    /// 私钥为 BIP-32 主私钥，种子通常来自 BIP-39 助记词 `Mnemonic::to_seed`
+    /// The private key is the BIP-32 master key; the seed usually comes from a BIP-39 mnemonic
+    /// via `Mnemonic::to_seed`
+    ///
+    /// 参数 / Parameters:
+    /// - seed: 16到64字节的种子 / 16 to 64 byte seed
+    pub fn generate_private_key_from_seed(seed: &[u8]) -> Result<String, EcdsaError> {
+        let master = ExtendedPrivateKey::from_seed(seed, Bip32Network::Mainnet)
+            .map_err(|_| EcdsaError::KeyGenerationError)?;
+        Ok(master.private_key())
+    }
+
+    /// 将BASE64私钥导出为 WIF（Wallet Import Format）/ Export a BASE64 private key as WIF
+    ///
+    /// WIF = Base58Check(前缀 || 私钥 || [0x01])，压缩标志表示对应公钥使用压缩编码
+    /// WIF = Base58Check(prefix || key || [0x01]); the flag marks a compressed public key
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - compressed: 对应公钥是否压缩 / Whether the matching public key is compressed
+    /// - network: 主网或测试网 / Mainnet or testnet
+    pub fn private_key_to_wif(
+        private_key: &str,
+        compressed: bool,
+        network: WifNetwork,
+    ) -> Result<String, EcdsaError> {
+        Ok(PrivateKey::from_base64(private_key)?.to_wif(compressed, network))
+    }
+
+    /// 导入 WIF 私钥，返回BASE64私钥、是否压缩及网络
+    /// Import a WIF private key, returning the BASE64 key, compression flag and network
+    pub fn private_key_from_wif(wif: &str) -> Result<(String, bool, WifNetwork), EcdsaError> {
+        let (private_key, compressed, network) = PrivateKey::from_wif(wif)?;
+        Ok((private_key.to_base64(), compressed, network))
     }
 
     /// 从私钥生成公钥 / Generate public key from private key
@@ -48,47 +198,252 @@ impl ECDSAAlgorithm {
     /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
     /// - compressed: 是否生成压缩公钥 / Whether to generate compressed public key
     pub fn generate_public_key(private_key: &str, compressed: bool) -> Result<String, EcdsaError> {
-        let bytes = general_purpose::STANDARD.decode(private_key)?;
-        let signing_key =
-            SigningKey::from_bytes(&bytes).map_err(|_| EcdsaError::KeyGenerationError)?;
+        let public_key = PrivateKey::from_base64(private_key)?.public_key();
+        Ok(public_key.with_compression(compressed).to_base64())
+    }
 
-        let verifying_key = signing_key.verifying_key();
-        let point = verifying_key.to_encoded_point(compressed);
-        Ok(general_purpose::STANDARD.encode(point.as_bytes()))
+    /// 计算 ECDH 共享密钥 / Compute an ECDH shared secret
+    ///
+    /// 双方用自己的私钥和对方的公钥得到相同的32字节x坐标，使用前应经过 KDF
+    /// Both parties get the same 32-byte x-coordinate from their own private key and the other
+    /// party's public key; pass it through a KDF before use
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - public_key: BASE64编码的对方公钥 / BASE64 encoded public key of the peer
+    pub fn shared_secret(private_key: &str, public_key: &str) -> Result<String, EcdsaError> {
+        let private_key = PrivateKey::from_base64(private_key)?;
+        let public_key = PublicKey::from_base64(public_key)?;
+        Ok(general_purpose::STANDARD.encode(private_key.diffie_hellman(&public_key).as_ref()))
     }
 
-    /// 生成比特币风格地址 / Generate Bitcoin-style address
+    /// 生成比特币主网 P2PKH 地址 / Generate Bitcoin mainnet P2PKH address
     pub fn get_address(public_key: &str) -> Result<String, EcdsaError> {
+        Self::get_address_with_version(public_key, Base58Version::Mainnet)
+    }
+
+    /// 使用指定版本字节生成 Base58Check 地址 / Generate Base58Check address with a version byte
+    ///
+    /// 参数 / Parameters:
+    /// - public_key: BASE64编码的公钥 / BASE64 encoded public key
+    /// - version: 网络版本字节 / Network version byte
+    pub fn get_address_with_version(
+        public_key: &str,
+        version: Base58Version,
+    ) -> Result<String, EcdsaError> {
         let pub_bytes = general_purpose::STANDARD.decode(public_key)?;
         let point = EncodedPoint::<Secp256k1>::from_bytes(pub_bytes)
             .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
 
-        // SHA-256哈希 / SHA-256 hash
-        let mut sha256 = Sha256::new();
-        sha256.update(point.as_bytes());
-        let sha_result = sha256.finalize();
+        // Base58Check编码 / Base58Check encoding
+        Ok(Base58Algorithm::encode_check(
+            version.to_byte(),
+            &Self::hash160(point.as_bytes()),
+        ))
+    }
 
-        // RIPEMD-160哈希 / RIPEMD-160 hash
-        let mut ripemd = Ripemd160::new();
-        ripemd.update(sha_result);
-        let ripemd_result = ripemd.finalize();
+    /// 生成 SegWit v0 P2WPKH 地址，仅支持压缩公钥 / Generate SegWit v0 P2WPKH address
+    ///
+    /// 参数 / Parameters:
+    /// - public_key: BASE64编码的压缩公钥 / BASE64 encoded compressed public key
+    /// - hrp: 人类可读部分，如 "bc" 或 "tb" / Human-readable part such as "bc" or "tb"
+    pub fn get_segwit_address(public_key: &str, hrp: &str) -> Result<String, EcdsaError> {
+        let pub_bytes = general_purpose::STANDARD.decode(public_key)?;
+        let point = EncodedPoint::<Secp256k1>::from_bytes(pub_bytes)
+            .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
+        if !point.is_compressed() {
+            return Err(EcdsaError::AddressError(
+                "P2WPKH 需要压缩公钥 / P2WPKH requires a compressed public key".to_string(),
+            ));
+        }
 
-        // Base58编码 / Base58 encoding
-        Ok(Base58Algorithm::encode(&ripemd_result))
+        Bech32Algorithm::encode_segwit_address(hrp, 0, &Self::hash160(point.as_bytes()))
+            .map_err(|e| EcdsaError::AddressError(e.to_string()))
     }
 
-    /// 生成ECDSA签名 / Generate ECDSA signature
+    /// 解析传统 Base58Check 地址或 SegWit 地址 / Parse a legacy Base58Check or SegWit address
+    ///
+    /// SegWit 地址支持主网 "bc" 和测试网 "tb" 前缀；Ed25519 和 SM2 地址不是 secp256k1 地址，
+    /// 返回 `AddressError`
+    /// SegWit addresses are accepted with the mainnet "bc" and testnet "tb" prefixes; Ed25519
+    /// and SM2 addresses are not secp256k1 addresses and fail with `AddressError`
+    pub fn parse_address(address: &str) -> Result<AddressKind, EcdsaError> {
+        let lower = address.to_ascii_lowercase();
+        for hrp in [MAINNET_HRP, TESTNET_HRP] {
+            if lower.starts_with(&format!("{}1", hrp)) {
+                let (witness_version, program) =
+                    Bech32Algorithm::decode_segwit_address(hrp, address)
+                        .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
+                return Ok(AddressKind::SegWit {
+                    hrp: hrp.to_string(),
+                    witness_version,
+                    program,
+                });
+            }
+        }
+
+        let (version, hash) = Base58Algorithm::decode_check(address)
+            .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
+        let version = Base58Version::from_byte(version)
+            .filter(|v| !matches!(v, Base58Version::Ed25519 | Base58Version::Sm2))
+            .ok_or_else(|| {
+                EcdsaError::AddressError(format!(
+                    "未知的版本字节 / Unknown version byte: {:#04x}",
+                    version
+                ))
+            })?;
+        Self::validate_address(address, version)?;
+        Ok(AddressKind::Base58 { version, hash })
+    }
+
+    /// 校验地址并返回其HASH160 / Validate an address and return its HASH160
+    ///
+    /// 校验和错误、版本字节不符或长度错误时返回 `AddressError`
+    /// Returns `AddressError` on checksum mismatch, wrong version byte or bad length
+    pub fn validate_address(address: &str, version: Base58Version) -> Result<Vec<u8>, EcdsaError> {
+        Base58Algorithm::validate_address(address, version)
+            .map_err(|e| EcdsaError::AddressError(e.to_string()))
+    }
+
+    /// 生成ECDSA签名，s 规范化为低S值 / Generate ECDSA signature with s normalised to low-S
     pub fn sign(private_key: &str, data: &[u8]) -> Result<String, EcdsaError> {
+        Ok(PrivateKey::from_base64(private_key)?.sign(data).to_base64())
+    }
+
+    /// 验证ECDSA签名 / Verify ECDSA signature
+    ///
+    /// 宽松模式：接受任何可解析的DER编码，高S值签名验证失败（返回 `false`）
+    /// Lenient mode: accepts any DER encoding that parses; a high-S signature fails to verify
+    /// (returns `false`)
+    pub fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, EcdsaError> {
+        Self::verify_with(public_key, &Sha256::digest(data), signature, false)
+    }
+
+    /// 严格验证ECDSA签名 / Strictly verify ECDSA signature
+    ///
+    /// 拒绝不符合 BIP-66 的DER编码（`Der*` 错误）和高S值签名（`HighS`），
+    /// 防止第三方在不使签名失效的情况下修改交易ID
+    /// Rejects DER that is not BIP-66 canonical (the `Der*` errors) and high-S signatures
+    /// (`HighS`), so a third party cannot change a transaction ID without invalidating it
+    pub fn verify_strict(
+        public_key: &str,
+        data: &[u8],
+        signature: &str,
+    ) -> Result<bool, EcdsaError> {
+        Self::verify_with(public_key, &Sha256::digest(data), signature, true)
+    }
+
+    /// 对32字节消息哈希生成DER格式ECDSA签名 / Generate a DER ECDSA signature over a 32-byte hash
+    ///
+    /// 用于已计算好摘要的数据，如双重SHA-256后的交易ID；`sign(data)` 等价于
+    /// `sign_prehash(SHA-256(data))`
+    /// For data that is already digested, such as a double-SHA256 transaction ID;
+    /// `sign(data)` is equivalent to `sign_prehash(SHA-256(data))`
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - message_hash: 32字节消息哈希 / 32-byte message hash
+    pub fn sign_prehash(private_key: &str, message_hash: &[u8]) -> Result<String, EcdsaError> {
+        Self::check_hash_len(message_hash)?;
+        let private_key = PrivateKey::from_base64(private_key)?;
+        Ok(private_key.sign_prehash(message_hash)?.to_base64())
+    }
+
+    /// 验证对32字节消息哈希的DER格式ECDSA签名（宽松模式）
+    /// Verify a DER ECDSA signature over a 32-byte hash (lenient mode)
+    ///
+    /// 参数 / Parameters:
+    /// - public_key: BASE64编码的公钥 / BASE64 encoded public key
+    /// - message_hash: 32字节消息哈希 / 32-byte message hash
+    /// - signature: BASE64编码的DER签名 / BASE64 encoded DER signature
+    pub fn verify_prehash(
+        public_key: &str,
+        message_hash: &[u8],
+        signature: &str,
+    ) -> Result<bool, EcdsaError> {
+        Self::check_hash_len(message_hash)?;
+        Self::verify_with(public_key, message_hash, signature, false)
+    }
+
+    /// 严格验证对32字节消息哈希的DER格式ECDSA签名，规则同 `verify_strict`
+    /// Strictly verify a DER ECDSA signature over a 32-byte hash, same rules as `verify_strict`
+    pub fn verify_prehash_strict(
+        public_key: &str,
+        message_hash: &[u8],
+        signature: &str,
+    ) -> Result<bool, EcdsaError> {
+        Self::check_hash_len(message_hash)?;
+        Self::verify_with(public_key, message_hash, signature, true)
+    }
+
+    /// 对32字节消息哈希生成65字节可恢复签名 / Generate a 65-byte recoverable signature
+    ///
+    /// 返回BASE64编码的 r || s || v，v 为恢复ID（0或1），s 已规范化为低S值
+    /// Returns BASE64 encoded r || s || v, where v is the recovery id (0 or 1) and s is low-S
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - message_hash: 32字节消息哈希 / 32-byte message hash
+    pub fn sign_recoverable(private_key: &str, message_hash: &[u8]) -> Result<String, EcdsaError> {
+        Self::check_hash_len(message_hash)?;
         let bytes = general_purpose::STANDARD.decode(private_key)?;
         let signing_key =
             SigningKey::from_bytes(&bytes).map_err(|_| EcdsaError::KeyGenerationError)?;
 
-        let signature: Signature = signing_key.sign(data);
-        Ok(general_purpose::STANDARD.encode(signature.to_der().as_bytes()))
+        let signature: recoverable::Signature = signing_key
+            .sign_prehash(message_hash)
+            .map_err(|_| EcdsaError::SigningError)?;
+        Ok(general_purpose::STANDARD.encode(signature.as_ref()))
     }
 
-    /// 验证ECDSA签名 / Verify ECDSA signature
-    pub fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, EcdsaError> {
+    /// 从可恢复签名中恢复签名者公钥 / Recover the signer's public key from a recoverable signature
+    ///
+    /// v 接受 0/1 以及以太坊风格的 27/28
+    /// v accepts 0/1 as well as Ethereum-style 27/28
+    ///
+    /// 参数 / Parameters:
+    /// - message_hash: 32字节消息哈希 / 32-byte message hash
+    /// - signature: BASE64编码的65字节可恢复签名 / BASE64 encoded 65-byte recoverable signature
+    /// - compressed: 是否返回压缩公钥 / Whether to return a compressed public key
+    pub fn recover_public_key(
+        message_hash: &[u8],
+        signature: &str,
+        compressed: bool,
+    ) -> Result<String, EcdsaError> {
+        Self::check_hash_len(message_hash)?;
+        let mut sig_bytes = general_purpose::STANDARD.decode(signature)?;
+        if sig_bytes.len() != RECOVERABLE_SIGNATURE_LEN {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        if sig_bytes[64] == 27 || sig_bytes[64] == 28 {
+            sig_bytes[64] -= 27;
+        }
+        let signature = recoverable::Signature::try_from(sig_bytes.as_slice())
+            .map_err(|_| EcdsaError::InvalidSignature)?;
+
+        let verifying_key = signature
+            .recover_verifying_key_from_digest_bytes(message_hash.into())
+            .map_err(|_| EcdsaError::RecoveryFailed)?;
+        verifying_key
+            .verify_prehash(message_hash, &signature)
+            .map_err(|_| EcdsaError::RecoveryFailed)?;
+        let point = verifying_key.to_encoded_point(compressed);
+        Ok(general_purpose::STANDARD.encode(point.as_bytes()))
+    }
+
+    /// 从可恢复签名推导签名者的主网地址 / Derive the signer's mainnet address from a recoverable signature
+    pub fn recover_address(message_hash: &[u8], signature: &str) -> Result<String, EcdsaError> {
+        let public_key = Self::recover_public_key(message_hash, signature, true)?;
+        Self::get_address(&public_key)
+    }
+
+    /// 按指定模式验证DER签名 / Verify a DER signature in the given mode
+    fn verify_with(
+        public_key: &str,
+        message_hash: &[u8],
+        signature: &str,
+        strict: bool,
+    ) -> Result<bool, EcdsaError> {
         let pub_bytes = general_purpose::STANDARD.decode(public_key)?;
         let point = EncodedPoint::<Secp256k1>::from_bytes(pub_bytes)
             .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
@@ -97,9 +452,102 @@ impl ECDSAAlgorithm {
             VerifyingKey::from_encoded_point(&point).map_err(|_| EcdsaError::VerificationFailed)?;
 
         let sig_bytes = general_purpose::STANDARD.decode(signature)?;
+        if strict {
+            Self::check_canonical_der(&sig_bytes)?;
+        }
         let signature =
             Signature::from_der(&sig_bytes).map_err(|_| EcdsaError::VerificationFailed)?;
+        // 签名原样交给 k256，其验证器拒绝高S值；严格模式改为返回明确的错误
+        // The signature goes to k256 unchanged, whose verifier rejects high-S; strict mode
+        // reports it as an explicit error instead
+        if strict && signature.normalize_s().is_some() {
+            return Err(EcdsaError::HighS);
+        }
+
+        Ok(verifying_key
+            .verify_prehash(message_hash, &signature)
+            .is_ok())
+    }
+
+    /// 按 BIP-66 校验DER编码是否规范 / Check that the DER encoding is canonical per BIP-66
+    ///
+    /// 格式 / Format: 0x30 [总长] 0x02 [R长] [R] 0x02 [S长] [S]
+    fn check_canonical_der(sig: &[u8]) -> Result<(), EcdsaError> {
+        if sig.len() < 8 || sig.len() > 72 {
+            return Err(EcdsaError::DerInvalidLength);
+        }
+        if sig[0] != 0x30 || sig[1] as usize != sig.len() - 2 {
+            return Err(EcdsaError::DerInvalidSequence);
+        }
+        let len_r = sig[3] as usize;
+        if 5 + len_r >= sig.len() {
+            return Err(EcdsaError::DerROverflow);
+        }
+        let len_s = sig[5 + len_r] as usize;
+        if len_r + len_s + 6 != sig.len() {
+            return Err(EcdsaError::DerLengthMismatch);
+        }
+        for (offset, len) in [(2, len_r), (4 + len_r, len_s)] {
+            let int = &sig[offset + 2..offset + 2 + len];
+            if sig[offset] != 0x02 || len == 0 {
+                return Err(EcdsaError::DerInvalidInteger);
+            }
+            if int[0] & 0x80 != 0 {
+                return Err(EcdsaError::DerNegativeInteger);
+            }
+            if len > 1 && int[0] == 0 && int[1] & 0x80 == 0 {
+                return Err(EcdsaError::DerExcessLeadingZero);
+            }
+        }
+        Ok(())
+    }
+
+    /// 校验消息哈希长度 / Check the message hash length
+    pub(crate) fn check_hash_len(message_hash: &[u8]) -> Result<(), EcdsaError> {
+        if message_hash.len() != MESSAGE_HASH_LEN {
+            return Err(EcdsaError::InvalidHashLength(message_hash.len()));
+        }
+        Ok(())
+    }
+
+    /// 计算 SHA-256 后再计算 RIPEMD-160 / Compute SHA-256 followed by RIPEMD-160
+    pub(crate) fn hash160(data: &[u8]) -> Vec<u8> {
+        // SHA-256哈希 / SHA-256 hash
+        let mut sha256 = Sha256::new();
+        sha256.update(data);
+        let sha_result = sha256.finalize();
+
+        // RIPEMD-160哈希 / RIPEMD-160 hash
+        let mut ripemd = Ripemd160::new();
+        ripemd.update(sha_result);
+        ripemd.finalize().to_vec()
+    }
+}
+
+impl SignatureScheme for ECDSAAlgorithm {
+    const NAME: &'static str = "secp256k1-ecdsa";
+
+    type Error = EcdsaError;
+
+    fn generate_private_key() -> String {
+        ECDSAAlgorithm::generate_private_key()
+    }
+
+    /// 返回压缩公钥 / Returns the compressed public key
+    fn derive_public_key(private_key: &str) -> Result<String, EcdsaError> {
+        ECDSAAlgorithm::generate_public_key(private_key, true)
+    }
+
+    fn sign(private_key: &str, data: &[u8]) -> Result<String, EcdsaError> {
+        ECDSAAlgorithm::sign(private_key, data)
+    }
+
+    fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, EcdsaError> {
+        ECDSAAlgorithm::verify(public_key, data, signature)
+    }
 
-        Ok(verifying_key.verify(data, &signature).is_ok())
+    /// 返回主网 P2PKH 地址 / Returns the mainnet P2PKH address
+    fn address(public_key: &str) -> Result<String, EcdsaError> {
+        ECDSAAlgorithm::get_address(public_key)
     }
 }
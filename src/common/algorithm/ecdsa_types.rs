@@ -0,0 +1,455 @@
+/*
+ * ECDSA 强类型密钥、签名与地址 / Typed ECDSA keys, signatures and addresses
+ *
+ * `ECDSAAlgorithm` 的函数以BASE64字符串传递密钥和签名，每次调用都要重新解析，且容易混淆参数。
+ * 这里的新类型只在构造时解析一次，并提供十六进制、BASE64、原始字节和 serde 转换。
+ * 字符串形式与 `ECDSAAlgorithm` 保持一致：私钥为32字节，公钥为SEC1编码，签名为DER编码。
+ * The `ECDSAAlgorithm` functions pass keys and signatures as BASE64 strings, re-parsing them on
+ * every call and making arguments easy to mix up. These newtypes parse once on construction and
+ * offer hex, BASE64, raw byte and serde conversions. String forms match `ECDSAAlgorithm`:
+ * 32-byte private keys, SEC1 public keys and DER signatures.
+ *
+ * `PrivateKey` 在释放时清零，`Debug` 不输出内容，也不实现 `Display`，导出时须显式调用 `to_base64`/`to_hex`/`to_wif`。
+ * `PrivateKey` is zeroized on drop, redacted in `Debug` and deliberately has no `Display`;
+ * exporting it requires an explicit `to_base64`/`to_hex`/`to_wif` call.
+ */
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use crate::common::algorithm::ecdsa_algorithm::{
+    AddressKind, ECDSAAlgorithm, EcdsaError, WifNetwork, WIF_COMPRESSED_FLAG,
+};
+use base64::{engine::general_purpose, Engine as _};
+use k256::ecdh::diffie_hellman;
+use k256::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    signature::Signer,
+    SigningKey, VerifyingKey,
+};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+/// SECP256K1 私钥，释放时清零 / SECP256K1 private key, zeroized on drop
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKey(SigningKey);
+
+/// SECP256K1 公钥，记录是否以压缩形式编码 / SECP256K1 public key remembering its compression
+#[derive(Clone, Copy, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct PublicKey {
+    key: VerifyingKey,
+    compressed: bool,
+}
+
+/// ECDSA 签名，s 总是低S值，解析高S值签名返回 `HighS`
+/// ECDSA signature, s is always low-S; parsing a high-S signature fails with `HighS`
+#[derive(Clone, Copy, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct Signature(k256::ecdsa::Signature);
+
+/// 经过校验的 Base58Check 或 SegWit 地址 / Validated Base58Check or SegWit address
+#[derive(Clone, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct Address {
+    encoded: String,
+    kind: AddressKind,
+}
+
+impl PrivateKey {
+    /// 生成随机私钥 / Generate a random private key
+    pub fn generate() -> Self {
+        PrivateKey(SigningKey::random(&mut OsRng))
+    }
+
+    /// 从32字节原始私钥创建 / Create from 32 raw bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        SigningKey::from_bytes(bytes)
+            .map(PrivateKey)
+            .map_err(|_| EcdsaError::KeyGenerationError)
+    }
+
+    /// 从十六进制字符串创建 / Create from a hex string
+    pub fn from_hex(hex: &str) -> Result<Self, EcdsaError> {
+        Self::from_bytes(&Zeroizing::new(decode_hex(hex)?))
+    }
+
+    /// 从BASE64字符串创建 / Create from a BASE64 string
+    pub fn from_base64(base64: &str) -> Result<Self, EcdsaError> {
+        Self::from_bytes(&Zeroizing::new(general_purpose::STANDARD.decode(base64)?))
+    }
+
+    /// 32字节原始私钥，返回值释放时清零 / 32 raw bytes, zeroized when the result is dropped
+    pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.0.to_bytes().into())
+    }
+
+    /// 十六进制字符串 / Hex string
+    pub fn to_hex(&self) -> String {
+        encode_hex(self.to_bytes().as_ref())
+    }
+
+    /// BASE64字符串，与 `ECDSAAlgorithm` 的私钥格式相同 / BASE64 string as used by `ECDSAAlgorithm`
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(self.to_bytes().as_ref())
+    }
+
+    /// 导出为 WIF / Export as WIF
+    pub fn to_wif(&self, compressed: bool, network: WifNetwork) -> String {
+        let mut payload = Zeroizing::new(self.to_bytes().to_vec());
+        if compressed {
+            payload.push(WIF_COMPRESSED_FLAG);
+        }
+        Base58Algorithm::encode_check(network.to_byte(), &payload)
+    }
+
+    /// 从 WIF 导入，返回私钥、是否压缩及网络 / Import from WIF with compression flag and network
+    pub fn from_wif(wif: &str) -> Result<(Self, bool, WifNetwork), EcdsaError> {
+        let (prefix, payload) = Base58Algorithm::decode_check(wif)
+            .map_err(|e| EcdsaError::InvalidWif(e.to_string()))?;
+        let payload = Zeroizing::new(payload);
+        let network = WifNetwork::from_byte(prefix).ok_or_else(|| {
+            EcdsaError::InvalidWif(format!("未知的前缀 / Unknown prefix: {:#04x}", prefix))
+        })?;
+        let compressed = match (payload.len(), payload.last()) {
+            (32, _) => false,
+            (33, Some(&WIF_COMPRESSED_FLAG)) => true,
+            _ => {
+                return Err(EcdsaError::InvalidWif(format!(
+                    "负载长度或压缩标志错误 / Bad payload length or compression flag: {} bytes",
+                    payload.len()
+                )))
+            }
+        };
+        Ok((Self::from_bytes(&payload[..32])?, compressed, network))
+    }
+
+    /// 对应的压缩公钥 / Matching compressed public key
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            key: self.0.verifying_key(),
+            compressed: true,
+        }
+    }
+
+    /// ECDH 共享密钥：与对方公钥相乘所得点的32字节x坐标
+    /// ECDH shared secret: the 32-byte x-coordinate of this key times the peer's public key
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> Zeroizing<[u8; 32]> {
+        let secret = k256::SecretKey::from(&self.0);
+        let point = k256::PublicKey::from(&public_key.key);
+        let shared = diffie_hellman(secret.to_nonzero_scalar(), point.as_affine());
+        Zeroizing::new((*shared.raw_secret_bytes()).into())
+    }
+
+    /// 对数据签名，数据先做 SHA-256 / Sign data, hashed with SHA-256 first
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        Signature::normalized(self.0.sign(data))
+    }
+
+    /// 对32字节消息哈希签名 / Sign a 32-byte message hash
+    pub fn sign_prehash(&self, message_hash: &[u8]) -> Result<Signature, EcdsaError> {
+        ECDSAAlgorithm::check_hash_len(message_hash)?;
+        let signature = self
+            .0
+            .sign_prehash(message_hash)
+            .map_err(|_| EcdsaError::SigningError)?;
+        Ok(Signature::normalized(signature))
+    }
+}
+
+/// 内部的 `SigningKey` 在释放时清零标量 / The inner `SigningKey` zeroizes its scalar on drop
+impl ZeroizeOnDrop for PrivateKey {}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrivateKey(<redacted>)")
+    }
+}
+
+impl FromStr for PrivateKey {
+    type Err = EcdsaError;
+
+    /// 解析BASE64编码的私钥 / Parse a BASE64 encoded private key
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(s)
+    }
+}
+
+impl Serialize for PrivateKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Zeroizing::new(self.to_base64()))
+    }
+}
+
+impl<'de> Deserialize<'de> for PrivateKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = Zeroizing::new(String::deserialize(deserializer)?);
+        Self::from_base64(&encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+impl PublicKey {
+    /// 从SEC1编码（33字节压缩或65字节非压缩）创建 / Create from SEC1 bytes (33 or 65 bytes)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let key = VerifyingKey::from_sec1_bytes(bytes)
+            .map_err(|e| EcdsaError::AddressError(e.to_string()))?;
+        Ok(PublicKey {
+            key,
+            compressed: bytes.len() == 33,
+        })
+    }
+
+    /// 从十六进制字符串创建 / Create from a hex string
+    pub fn from_hex(hex: &str) -> Result<Self, EcdsaError> {
+        Self::from_bytes(&decode_hex(hex)?)
+    }
+
+    /// 从BASE64字符串创建 / Create from a BASE64 string
+    pub fn from_base64(base64: &str) -> Result<Self, EcdsaError> {
+        Self::from_bytes(&general_purpose::STANDARD.decode(base64)?)
+    }
+
+    /// SEC1编码 / SEC1 encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.key
+            .to_encoded_point(self.compressed)
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// 十六进制字符串 / Hex string
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.to_bytes())
+    }
+
+    /// BASE64字符串，与 `ECDSAAlgorithm` 的公钥格式相同 / BASE64 string as used by `ECDSAAlgorithm`
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(self.to_bytes())
+    }
+
+    /// 是否为压缩编码 / Whether the key is compressed
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// 以指定压缩形式编码的同一公钥 / The same key with the given compression
+    pub fn with_compression(&self, compressed: bool) -> Self {
+        PublicKey {
+            key: self.key,
+            compressed,
+        }
+    }
+
+    /// 验证对数据的签名 / Verify a signature over data
+    pub fn verify(&self, data: &[u8], signature: &Signature) -> bool {
+        self.verify_prehash(&Sha256::digest(data), signature)
+            .unwrap_or(false)
+    }
+
+    /// 验证对32字节消息哈希的签名 / Verify a signature over a 32-byte message hash
+    pub fn verify_prehash(
+        &self,
+        message_hash: &[u8],
+        signature: &Signature,
+    ) -> Result<bool, EcdsaError> {
+        ECDSAAlgorithm::check_hash_len(message_hash)?;
+        Ok(self.key.verify_prehash(message_hash, &signature.0).is_ok())
+    }
+
+    /// 主网 P2PKH 地址 / Mainnet P2PKH address
+    pub fn address(&self) -> Address {
+        self.address_with_version(Base58Version::Mainnet)
+    }
+
+    /// 使用指定版本字节的 Base58Check 地址 / Base58Check address with a version byte
+    pub fn address_with_version(&self, version: Base58Version) -> Address {
+        let hash = ECDSAAlgorithm::hash160(&self.to_bytes());
+        Address {
+            encoded: Base58Algorithm::encode_check(version.to_byte(), &hash),
+            kind: AddressKind::Base58 { version, hash },
+        }
+    }
+
+    /// SegWit v0 P2WPKH 地址，仅支持压缩公钥 / SegWit v0 P2WPKH address, compressed keys only
+    pub fn segwit_address(&self, hrp: &str) -> Result<Address, EcdsaError> {
+        ECDSAAlgorithm::get_segwit_address(&self.to_base64(), hrp)?.parse()
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PublicKey({})", self.to_hex())
+    }
+}
+
+impl fmt::Display for PublicKey {
+    /// BASE64编码 / BASE64 encoding
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_base64())
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = EcdsaError;
+
+    /// 解析BASE64编码的公钥 / Parse a BASE64 encoded public key
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(s)
+    }
+}
+
+impl Signature {
+    /// 从DER编码创建，拒绝高S值 / Create from DER, rejecting high-S
+    pub fn from_der(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        k256::ecdsa::Signature::from_der(bytes)
+            .map_err(|_| EcdsaError::InvalidSignature)
+            .and_then(Self::low_s)
+    }
+
+    /// 从64字节 r || s 创建，拒绝高S值 / Create from 64-byte r || s, rejecting high-S
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        k256::ecdsa::Signature::try_from(bytes)
+            .map_err(|_| EcdsaError::InvalidSignature)
+            .and_then(Self::low_s)
+    }
+
+    /// 从十六进制DER编码创建 / Create from hex-encoded DER
+    pub fn from_hex(hex: &str) -> Result<Self, EcdsaError> {
+        Self::from_der(&decode_hex(hex)?)
+    }
+
+    /// 从BASE64 DER编码创建 / Create from BASE64-encoded DER
+    pub fn from_base64(base64: &str) -> Result<Self, EcdsaError> {
+        Self::from_der(&general_purpose::STANDARD.decode(base64)?)
+    }
+
+    /// DER编码 / DER encoding
+    pub fn to_der(&self) -> Vec<u8> {
+        self.0.to_der().as_bytes().to_vec()
+    }
+
+    /// 64字节 r || s / 64-byte r || s
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0.as_ref().try_into().unwrap()
+    }
+
+    /// 十六进制DER编码 / Hex-encoded DER
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.to_der())
+    }
+
+    /// BASE64 DER编码，与 `ECDSAAlgorithm::sign` 的输出相同 / BASE64 DER as returned by `ECDSAAlgorithm::sign`
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(self.to_der())
+    }
+
+    /// 将新生成的签名规范化为低S值 / Normalise a freshly produced signature to low-S
+    fn normalized(signature: k256::ecdsa::Signature) -> Self {
+        Signature(signature.normalize_s().unwrap_or(signature))
+    }
+
+    /// 接受低S值签名；外部传入的高S值签名可被延展，原样拒绝而不是改写
+    /// Accept a low-S signature; a high-S signature from outside is malleable and is rejected
+    /// rather than rewritten
+    fn low_s(signature: k256::ecdsa::Signature) -> Result<Self, EcdsaError> {
+        match signature.normalize_s() {
+            Some(_) => Err(EcdsaError::HighS),
+            None => Ok(Signature(signature)),
+        }
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Signature({})", self.to_hex())
+    }
+}
+
+impl fmt::Display for Signature {
+    /// BASE64 DER编码 / BASE64-encoded DER
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_base64())
+    }
+}
+
+impl FromStr for Signature {
+    type Err = EcdsaError;
+
+    /// 解析BASE64 DER编码的签名 / Parse a BASE64-encoded DER signature
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(s)
+    }
+}
+
+impl Address {
+    /// 地址字符串 / Address string
+    pub fn as_str(&self) -> &str {
+        &self.encoded
+    }
+
+    /// 地址类型及内容 / Address kind and contents
+    pub fn kind(&self) -> &AddressKind {
+        &self.kind
+    }
+
+    /// 公钥哈希、脚本哈希或见证程序 / Public key hash, script hash or witness program
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match &self.kind {
+            AddressKind::Base58 { hash, .. } => hash.clone(),
+            AddressKind::SegWit { program, .. } => program.clone(),
+        }
+    }
+
+    /// 十六进制的哈希或见证程序 / Hex-encoded hash or witness program
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.to_bytes())
+    }
+
+    /// 地址的BASE64编码哈希或见证程序 / BASE64-encoded hash or witness program
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(self.to_bytes())
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Address({})", self.encoded)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encoded)
+    }
+}
+
+impl FromStr for Address {
+    type Err = EcdsaError;
+
+    /// 解析并校验 Base58Check 或 SegWit 地址 / Parse and validate a Base58Check or SegWit address
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = ECDSAAlgorithm::parse_address(s)?;
+        // SegWit 地址统一为小写 / SegWit addresses are normalised to lowercase
+        let encoded = match &kind {
+            AddressKind::SegWit { .. } => s.to_ascii_lowercase(),
+            AddressKind::Base58 { .. } => s.to_string(),
+        };
+        Ok(Address { encoded, kind })
+    }
+}
+
+/// 十六进制编码 / Hex encoding
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 十六进制解码，忽略大小写 / Hex decoding, case-insensitive
+fn decode_hex(hex: &str) -> Result<Vec<u8>, EcdsaError> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return Err(EcdsaError::HexError);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| EcdsaError::HexError))
+        .collect()
+}
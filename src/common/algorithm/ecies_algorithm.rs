@@ -0,0 +1,132 @@
+/*
+ * ECIES 混合加密工具类 / ECIES Hybrid Encryption Utilities
+ *
+ * 向SECP256K1公钥加密任意长度数据，用于私密交易和加密的P2P握手。
+ * Encrypts data of any length to a SECP256K1 public key, for private transactions and the
+ * encrypted P2P handshake.
+ *
+ * 加密流程 / Encryption steps:
+ * 1. 生成临时密钥对 (e, E) / Generate an ephemeral key pair (e, E)
+ * 2. ECDH: S = x(e · P)，P 为接收方公钥 / ECDH: S = x(e · P), P being the recipient key
+ * 3. HKDF-SHA256(IKM = S, salt = E, info = 标签 || P) 派生32字节密钥
+ *    HKDF-SHA256(IKM = S, salt = E, info = label || P) derives a 32-byte key
+ * 4. AES-256-GCM 加密，临时公钥作为关联数据，GCM 认证标签即为覆盖 E 和密文的 MAC
+ *    AES-256-GCM with E as associated data; the GCM tag is the MAC over E and the ciphertext
+ *
+ * 输出格式 / Output layout: `E(33, compressed) | AesCiphertext::to_bytes()`
+ */
+use crate::common::algorithm::aes_algorithm::{AESAlgorithm, AesCiphertext, AesMode};
+use crate::common::algorithm::ecdsa_algorithm::EcdsaError;
+use crate::common::algorithm::ecdsa_types::{PrivateKey, PublicKey};
+use openssl::error::ErrorStack;
+use openssl::md::Md;
+use openssl::pkey::Id;
+use openssl::pkey_ctx::PkeyCtx;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// 压缩临时公钥长度 / Length of the compressed ephemeral public key
+pub const EPHEMERAL_KEY_LEN: usize = 33;
+/// 派生的 AES-256 密钥长度 / Length of the derived AES-256 key
+const KEY_LEN: usize = 32;
+/// HKDF info 标签，区分协议版本 / HKDF info label separating protocol versions
+const HKDF_INFO_LABEL: &[u8] = b"blockchain-rs/ecies/v1";
+
+/// ECIES 错误类型 / ECIES error type
+#[derive(Debug, Error)]
+pub enum EciesError {
+    #[error(transparent)]
+    Ecdsa(#[from] EcdsaError),
+
+    #[error("密钥派生失败 / Key derivation failed: {0}")]
+    KeyDerivation(#[from] ErrorStack),
+
+    #[error("密文格式错误 / Malformed ciphertext: {0}")]
+    InvalidCiphertext(String),
+
+    #[error(
+        "解密失败，密钥不匹配或密文被篡改 / Decryption failed: wrong key or tampered ciphertext"
+    )]
+    DecryptionFailed,
+
+    #[error("AES加密失败 / AES encryption failed: {0}")]
+    EncryptionFailed(String),
+}
+
+pub struct EciesAlgorithm;
+impl EciesAlgorithm {
+    /// 使用接收方公钥加密数据 / Encrypt data to the recipient's public key
+    ///
+    /// 参数 / Parameters:
+    /// - public_key: BASE64编码的接收方公钥，压缩或非压缩均可 / BASE64 recipient public key
+    /// - data: 明文 / Plaintext
+    pub fn encrypt(public_key: &str, data: &[u8]) -> Result<Vec<u8>, EciesError> {
+        let recipient = PublicKey::from_base64(public_key)?;
+        let ephemeral = PrivateKey::generate();
+        let ephemeral_public = ephemeral.public_key().to_bytes();
+
+        let key = Self::derive_key(&ephemeral, &recipient, &recipient, &ephemeral_public)?;
+        let envelope = AESAlgorithm::encrypt(AesMode::Gcm, &key[..], data, Some(&ephemeral_public))
+            .map_err(|e| EciesError::EncryptionFailed(e.to_string()))?;
+
+        let mut output = ephemeral_public;
+        output.extend_from_slice(&envelope.to_bytes());
+        Ok(output)
+    }
+
+    /// 使用接收方私钥解密数据 / Decrypt data with the recipient's private key
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的接收方私钥 / BASE64 recipient private key
+    /// - ciphertext: `encrypt` 的输出 / Output of `encrypt`
+    pub fn decrypt(private_key: &str, ciphertext: &[u8]) -> Result<Vec<u8>, EciesError> {
+        let recipient = PrivateKey::from_base64(private_key)?;
+        if ciphertext.len() < EPHEMERAL_KEY_LEN {
+            return Err(EciesError::InvalidCiphertext(
+                "缺少临时公钥 / missing ephemeral public key".to_string(),
+            ));
+        }
+        let (ephemeral_public, envelope) = ciphertext.split_at(EPHEMERAL_KEY_LEN);
+        let ephemeral = PublicKey::from_bytes(ephemeral_public)?;
+        let envelope = AesCiphertext::from_bytes(envelope)
+            .map_err(|e| EciesError::InvalidCiphertext(e.to_string()))?;
+        if envelope.mode != AesMode::Gcm {
+            return Err(EciesError::InvalidCiphertext(format!(
+                "需要GCM模式 / GCM mode required, found {:?}",
+                envelope.mode
+            )));
+        }
+
+        // 解密方对临时公钥执行 ECDH / The decrypting side runs ECDH against E
+        let key = Self::derive_key(
+            &recipient,
+            &ephemeral,
+            &recipient.public_key(),
+            ephemeral_public,
+        )?;
+        AESAlgorithm::decrypt(&key[..], &envelope, Some(ephemeral_public))
+            .map_err(|_| EciesError::DecryptionFailed)
+    }
+
+    /// HKDF-SHA256(x(私钥 · 对方公钥), salt = E, info = 标签 || 接收方压缩公钥)
+    /// HKDF-SHA256(x(private key · peer key), salt = E, info = label || compressed recipient key)
+    fn derive_key(
+        private_key: &PrivateKey,
+        peer: &PublicKey,
+        recipient: &PublicKey,
+        ephemeral_public: &[u8],
+    ) -> Result<Zeroizing<[u8; KEY_LEN]>, EciesError> {
+        let shared = private_key.diffie_hellman(peer);
+        let mut ctx = PkeyCtx::new_id(Id::HKDF)?;
+        ctx.derive_init()?;
+        ctx.set_hkdf_md(Md::sha256())?;
+        ctx.set_hkdf_key(shared.as_ref())?;
+        ctx.set_hkdf_salt(ephemeral_public)?;
+        ctx.add_hkdf_info(HKDF_INFO_LABEL)?;
+        ctx.add_hkdf_info(&recipient.with_compression(true).to_bytes())?;
+
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        ctx.derive(Some(&mut key[..]))?;
+        Ok(key)
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * Ed25519 签名算法工具类 / Ed25519 Signature Algorithm Utilities
+ *
+ * 主要功能 / Main functionalities:
+ * 1. 生成32字节私钥及32字节公钥 / Generate 32-byte private keys and 32-byte public keys
+ * 2. Ed25519 签名与严格验证 / Ed25519 signing and strict verification
+ * 3. Base58Check 地址生成与校验，使用 Ed25519 专用版本字节
+ *    Base58Check address generation and validation under the Ed25519 version byte
+ */
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use crate::common::algorithm::signature_scheme::SignatureScheme;
+use base64::{engine::general_purpose, Engine as _};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use thiserror::Error;
+
+/// 私钥长度 / Private key length
+pub const ED25519_PRIVATE_KEY_LEN: usize = 32;
+/// 公钥长度 / Public key length
+pub const ED25519_PUBLIC_KEY_LEN: usize = 32;
+/// 签名长度 / Signature length
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Ed25519 错误类型 / Ed25519 error type
+#[derive(Debug, Error)]
+pub enum Ed25519Error {
+    #[error("Base64解码失败 / Base64 decode failed: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error("私钥长度必须为32字节 / Private key must be 32 bytes, got {0}")]
+    InvalidPrivateKey(usize),
+
+    #[error("公钥无效 / Invalid public key")]
+    InvalidPublicKey,
+
+    #[error("签名格式错误 / Invalid signature encoding")]
+    InvalidSignature,
+
+    #[error("地址错误 / Address error: {0}")]
+    AddressError(String),
+}
+
+pub struct Ed25519Algorithm;
+impl Ed25519Algorithm {
+    /// 生成BASE64编码的私钥 / Generate BASE64 encoded private key
+    pub fn generate_private_key() -> String {
+        let mut secret = [0u8; ED25519_PRIVATE_KEY_LEN];
+        OsRng.fill_bytes(&mut secret);
+        general_purpose::STANDARD.encode(secret)
+    }
+
+    /// 从私钥生成公钥 / Generate public key from private key
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    pub fn generate_public_key(private_key: &str) -> Result<String, Ed25519Error> {
+        let signing_key = Self::signing_key(private_key)?;
+        Ok(general_purpose::STANDARD.encode(signing_key.verifying_key().to_bytes()))
+    }
+
+    /// 生成比特币风格的 Base58Check 地址 / Generate a Bitcoin-style Base58Check address
+    ///
+    /// 地址为公钥的 HASH160，使用 `Base58Version::Ed25519` 版本字节（以 "E" 开头），
+    /// 不会与 secp256k1 地址混淆
+    /// The address is the HASH160 of the public key under the `Base58Version::Ed25519` version
+    /// byte (starting with "E"), so it cannot be mistaken for a secp256k1 address
+    pub fn get_address(public_key: &str) -> Result<String, Ed25519Error> {
+        let verifying_key = Self::verifying_key(public_key)?;
+        let sha = BaseAlgorithm::hash(HashAlgorithm::Sha256, verifying_key.as_bytes());
+        let hash = BaseAlgorithm::hash(HashAlgorithm::Ripemd160, &sha);
+        Ok(Base58Algorithm::encode_check(
+            Base58Version::Ed25519.to_byte(),
+            &hash,
+        ))
+    }
+
+    /// 校验 Ed25519 地址并返回其HASH160 / Validate an Ed25519 address and return its HASH160
+    ///
+    /// 其他签名方案的地址因版本字节不符返回 `AddressError`
+    /// Addresses of other signature schemes fail with `AddressError` on the version byte
+    pub fn validate_address(address: &str) -> Result<Vec<u8>, Ed25519Error> {
+        Base58Algorithm::validate_address(address, Base58Version::Ed25519)
+            .map_err(|e| Ed25519Error::AddressError(e.to_string()))
+    }
+
+    /// 生成Ed25519签名 / Generate Ed25519 signature
+    pub fn sign(private_key: &str, data: &[u8]) -> Result<String, Ed25519Error> {
+        let signing_key = Self::signing_key(private_key)?;
+        let signature: Signature = signing_key.sign(data);
+        Ok(general_purpose::STANDARD.encode(signature.to_bytes()))
+    }
+
+    /// 验证Ed25519签名 / Verify Ed25519 signature
+    ///
+    /// 使用严格验证，拒绝弱公钥和可延展的签名
+    /// Uses strict verification, rejecting weak public keys and malleable signatures
+    pub fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, Ed25519Error> {
+        let verifying_key = Self::verifying_key(public_key)?;
+        let sig_bytes = general_purpose::STANDARD.decode(signature)?;
+        let signature =
+            Signature::from_slice(&sig_bytes).map_err(|_| Ed25519Error::InvalidSignature)?;
+        Ok(verifying_key.verify_strict(data, &signature).is_ok())
+    }
+
+    /// 解析私钥 / Parse a private key
+    fn signing_key(private_key: &str) -> Result<SigningKey, Ed25519Error> {
+        let bytes = general_purpose::STANDARD.decode(private_key)?;
+        let secret: [u8; ED25519_PRIVATE_KEY_LEN] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| Ed25519Error::InvalidPrivateKey(bytes.len()))?;
+        Ok(SigningKey::from_bytes(&secret))
+    }
+
+    /// 解析公钥 / Parse a public key
+    fn verifying_key(public_key: &str) -> Result<VerifyingKey, Ed25519Error> {
+        let bytes = general_purpose::STANDARD.decode(public_key)?;
+        let bytes: [u8; ED25519_PUBLIC_KEY_LEN] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| Ed25519Error::InvalidPublicKey)?;
+        VerifyingKey::from_bytes(&bytes).map_err(|_| Ed25519Error::InvalidPublicKey)
+    }
+}
+
+impl SignatureScheme for Ed25519Algorithm {
+    const NAME: &'static str = "ed25519";
+
+    type Error = Ed25519Error;
+
+    fn generate_private_key() -> String {
+        Ed25519Algorithm::generate_private_key()
+    }
+
+    fn derive_public_key(private_key: &str) -> Result<String, Ed25519Error> {
+        Ed25519Algorithm::generate_public_key(private_key)
+    }
+
+    fn sign(private_key: &str, data: &[u8]) -> Result<String, Ed25519Error> {
+        Ed25519Algorithm::sign(private_key, data)
+    }
+
+    fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, Ed25519Error> {
+        Ed25519Algorithm::verify(public_key, data, signature)
+    }
+
+    fn address(public_key: &str) -> Result<String, Ed25519Error> {
+        Ed25519Algorithm::get_address(public_key)
+    }
+}
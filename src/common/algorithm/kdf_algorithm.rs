@@ -0,0 +1,324 @@
+use crate::common::algorithm::aes_algorithm::{AESAlgorithm, AesCiphertext, AesMode};
+use openssl::hash::MessageDigest;
+use openssl::pkcs5::{pbkdf2_hmac, scrypt};
+use openssl::rand::rand_bytes;
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use std::error::Error;
+
+/// 默认盐值长度（字节）
+/// Default salt length in bytes
+const SALT_LEN: usize = 32;
+/// PBKDF2 支持的伪随机函数名称
+/// Name of the pseudo-random function supported by PBKDF2
+const PBKDF2_PRF: &str = "hmac-sha256";
+/// PBKDF2 迭代次数上限，防止构造的参数耗尽 CPU
+/// Upper limit of PBKDF2 iterations, so crafted parameters cannot exhaust the CPU
+pub const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+/// scrypt 参数 n 的上限
+/// Upper limit of the scrypt `n` parameter
+pub const MAX_SCRYPT_N: u64 = 1 << 20;
+/// scrypt 参数 r 的上限
+/// Upper limit of the scrypt `r` parameter
+pub const MAX_SCRYPT_R: u32 = 32;
+/// scrypt 参数 p 的上限
+/// Upper limit of the scrypt `p` parameter
+pub const MAX_SCRYPT_P: u32 = 16;
+/// scrypt 所需内存 128*r*(n+p) 字节的上限（2 GiB）
+/// Upper limit of the 128*r*(n+p) bytes of memory scrypt needs (2 GiB)
+pub const MAX_SCRYPT_MEMORY: u64 = 2 << 30;
+/// 派生密钥长度上限（字节）
+/// Upper limit of the derived key length in bytes
+pub const MAX_DKLEN: usize = 64;
+/// scrypt 内存上限之外额外预留的空间
+/// Slack reserved on top of the scrypt memory requirement
+const SCRYPT_MEMORY_SLACK: u64 = 1024 * 1024;
+
+/// 口令密钥派生参数，随密文一起序列化，解密时只需提供口令
+/// Passphrase key derivation parameters, serialised alongside the ciphertext so decryption
+/// only needs the passphrase
+///
+/// JSON 格式与以太坊 keystore 的 `kdf`/`kdfparams` 字段保持一致。
+/// The JSON layout matches the `kdf`/`kdfparams` fields of an Ethereum keystore.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+pub enum KdfParams {
+    /// PBKDF2-HMAC-SHA256
+    Pbkdf2 {
+        /// 迭代次数
+        /// Iteration count
+        c: u32,
+        /// 派生密钥长度（字节）
+        /// Derived key length in bytes
+        dklen: usize,
+        /// 伪随机函数，目前仅支持 "hmac-sha256"
+        /// Pseudo-random function, only "hmac-sha256" is supported
+        prf: String,
+        /// 盐值
+        /// Salt
+        #[serde_as(as = "Hex")]
+        salt: Vec<u8>,
+    },
+    /// scrypt
+    Scrypt {
+        /// CPU/内存开销参数，必须为大于1的2的幂
+        /// CPU/memory cost, must be a power of two greater than 1
+        n: u64,
+        /// 块大小参数
+        /// Block size parameter
+        r: u32,
+        /// 并行度参数
+        /// Parallelisation parameter
+        p: u32,
+        /// 派生密钥长度（字节）
+        /// Derived key length in bytes
+        dklen: usize,
+        /// 盐值
+        /// Salt
+        #[serde_as(as = "Hex")]
+        salt: Vec<u8>,
+    },
+}
+
+impl KdfParams {
+    /// 使用随机盐值创建 PBKDF2-HMAC-SHA256 参数
+    /// Create PBKDF2-HMAC-SHA256 parameters with a random salt
+    ///
+    /// # 参数
+    /// * `iterations`: 迭代次数
+    /// * `dklen`: 派生密钥长度
+    /// # Parameters
+    /// * `iterations`: Iteration count
+    /// * `dklen`: Derived key length
+    pub fn pbkdf2(iterations: u32, dklen: usize) -> Result<Self, Box<dyn Error>> {
+        Ok(KdfParams::Pbkdf2 {
+            c: iterations,
+            dklen,
+            prf: PBKDF2_PRF.to_string(),
+            salt: Self::random_salt()?,
+        })
+    }
+
+    /// 使用随机盐值创建 scrypt 参数
+    /// Create scrypt parameters with a random salt
+    ///
+    /// # 参数
+    /// * `n`: CPU/内存开销参数
+    /// * `r`: 块大小参数
+    /// * `p`: 并行度参数
+    /// * `dklen`: 派生密钥长度
+    /// # Parameters
+    /// * `n`: CPU/memory cost
+    /// * `r`: Block size
+    /// * `p`: Parallelisation
+    /// * `dklen`: Derived key length
+    pub fn scrypt(n: u64, r: u32, p: u32, dklen: usize) -> Result<Self, Box<dyn Error>> {
+        Ok(KdfParams::Scrypt {
+            n,
+            r,
+            p,
+            dklen,
+            salt: Self::random_salt()?,
+        })
+    }
+
+    /// 推荐的 PBKDF2 参数：262144 次迭代，32 字节密钥
+    /// Recommended PBKDF2 parameters: 262144 iterations, 32-byte key
+    pub fn default_pbkdf2() -> Result<Self, Box<dyn Error>> {
+        Self::pbkdf2(262_144, 32)
+    }
+
+    /// 推荐的 scrypt 参数：n=262144, r=8, p=1，32 字节密钥
+    /// Recommended scrypt parameters: n=262144, r=8, p=1, 32-byte key
+    pub fn default_scrypt() -> Result<Self, Box<dyn Error>> {
+        Self::scrypt(262_144, 8, 1, 32)
+    }
+
+    /// 获取派生密钥长度
+    /// Get the derived key length
+    pub fn dklen(&self) -> usize {
+        match self {
+            KdfParams::Pbkdf2 { dklen, .. } | KdfParams::Scrypt { dklen, .. } => *dklen,
+        }
+    }
+
+    /// 生成随机盐值
+    /// Generate a random salt
+    fn random_salt() -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut salt = vec![0u8; SALT_LEN];
+        rand_bytes(&mut salt)?;
+        Ok(salt)
+    }
+}
+
+/// 口令加密后的密文，包含密钥派生参数和 AES 密文信封
+/// Passphrase-encrypted ciphertext holding the KDF parameters and the AES envelope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PassphraseCiphertext {
+    /// 密钥派生参数
+    /// Key derivation parameters
+    #[serde(flatten)]
+    pub kdf: KdfParams,
+    /// AES 密文信封
+    /// AES ciphertext envelope
+    pub cipher: AesCiphertext,
+}
+
+/// 口令密钥派生工具类，将口令转换为 AES 密钥
+/// Passphrase key derivation utility class turning a passphrase into an AES key
+pub struct KDFAlgorithm;
+
+impl KDFAlgorithm {
+    /// 根据参数从口令派生密钥。
+    /// Derive a key from a passphrase according to the parameters.
+    ///
+    /// # 参数
+    /// * `passphrase`: 口令
+    /// * `params`: 密钥派生参数
+    /// # 返回
+    /// * 成功时返回长度为 `params.dklen()` 的密钥，否则返回错误。
+    /// # Parameters
+    /// * `passphrase`: Passphrase
+    /// * `params`: Key derivation parameters
+    /// # Returns
+    /// * Returns a key of `params.dklen()` bytes on success, or an error on failure.
+    pub fn derive_key(passphrase: &[u8], params: &KdfParams) -> Result<Vec<u8>, Box<dyn Error>> {
+        Self::check_params(params)?;
+        let mut key = vec![0u8; params.dklen()];
+        match params {
+            KdfParams::Pbkdf2 { c, salt, .. } => {
+                pbkdf2_hmac(
+                    passphrase,
+                    salt,
+                    *c as usize,
+                    MessageDigest::sha256(),
+                    &mut key,
+                )?;
+            }
+            KdfParams::Scrypt { n, r, p, salt, .. } => {
+                // scrypt 需要约 128*r*(n+p) 字节内存，额外预留 1MB；check_params 已限制其大小
+                // scrypt needs roughly 128*r*(n+p) bytes of memory, plus 1MB of slack; the size
+                // is already bounded by check_params
+                let maxmem = Self::scrypt_memory(*n, *r, *p)
+                    .and_then(|memory| memory.checked_add(SCRYPT_MEMORY_SLACK))
+                    .ok_or("scrypt内存需求溢出")?;
+                scrypt(passphrase, salt, *n, *r as u64, *p as u64, maxmem, &mut key)?;
+            }
+        }
+        Ok(key)
+    }
+
+    /// 使用口令派生的密钥以 AES-GCM 加密数据。
+    /// Encrypt data with AES-GCM under a passphrase-derived key.
+    ///
+    /// # 参数
+    /// * `passphrase`: 口令
+    /// * `params`: 密钥派生参数，`dklen` 必须为16、24或32
+    /// * `data`: 要加密的明文数据
+    /// # 返回
+    /// * 成功时返回包含派生参数的密文，否则返回错误。
+    /// # Parameters
+    /// * `passphrase`: Passphrase
+    /// * `params`: Key derivation parameters, `dklen` must be 16, 24 or 32
+    /// * `data`: Plaintext data to be encrypted
+    /// # Returns
+    /// * Returns the ciphertext together with its KDF parameters, or an error on failure.
+    pub fn encrypt_with_passphrase(
+        passphrase: &[u8],
+        params: KdfParams,
+        data: &[u8],
+    ) -> Result<PassphraseCiphertext, Box<dyn Error>> {
+        let key = Self::derive_key(passphrase, &params)?;
+        let cipher = AESAlgorithm::encrypt(AesMode::Gcm, &key, data, None)?;
+        Ok(PassphraseCiphertext {
+            kdf: params,
+            cipher,
+        })
+    }
+
+    /// 使用口令解密密文，口令错误时认证标签校验失败。
+    /// Decrypt a ciphertext with a passphrase; a wrong passphrase fails tag verification.
+    ///
+    /// # 参数
+    /// * `passphrase`: 口令
+    /// * `ciphertext`: 口令加密后的密文
+    /// # 返回
+    /// * 成功时返回明文，否则返回错误。
+    /// # Parameters
+    /// * `passphrase`: Passphrase
+    /// * `ciphertext`: Passphrase-encrypted ciphertext
+    /// # Returns
+    /// * Returns the plaintext on success, or an error on failure.
+    pub fn decrypt_with_passphrase(
+        passphrase: &[u8],
+        ciphertext: &PassphraseCiphertext,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = Self::derive_key(passphrase, &ciphertext.kdf)?;
+        AESAlgorithm::decrypt(&key, &ciphertext.cipher, None)
+    }
+
+    /// 校验密钥派生参数，包括 `MAX_*` 常量规定的上限
+    /// Validate key derivation parameters, including the upper limits given by the `MAX_*`
+    /// constants
+    ///
+    /// 参数可能来自不可信的文件，解密前调用可拒绝会耗尽 CPU 或内存的参数。
+    /// Parameters may come from an untrusted file; calling this before decrypting rejects
+    /// parameters that would exhaust the CPU or memory.
+    pub fn check_params(params: &KdfParams) -> Result<(), Box<dyn Error>> {
+        match params {
+            KdfParams::Pbkdf2 { c, prf, salt, .. } => {
+                if *c == 0 {
+                    return Err("PBKDF2迭代次数必须大于0".into());
+                }
+                if *c > MAX_PBKDF2_ITERATIONS {
+                    return Err(format!("PBKDF2迭代次数不能超过{}", MAX_PBKDF2_ITERATIONS).into());
+                }
+                if prf != PBKDF2_PRF {
+                    return Err(format!("不支持的PBKDF2伪随机函数: {}", prf).into());
+                }
+                if salt.is_empty() {
+                    return Err("盐值不能为空".into());
+                }
+            }
+            KdfParams::Scrypt { n, r, p, salt, .. } => {
+                if *n < 2 || !n.is_power_of_two() {
+                    return Err("scrypt参数n必须为大于1的2的幂".into());
+                }
+                if *r == 0 || *p == 0 {
+                    return Err("scrypt参数r和p必须大于0".into());
+                }
+                if *n > MAX_SCRYPT_N || *r > MAX_SCRYPT_R || *p > MAX_SCRYPT_P {
+                    return Err(format!(
+                        "scrypt参数超出上限: n<={}, r<={}, p<={}",
+                        MAX_SCRYPT_N, MAX_SCRYPT_R, MAX_SCRYPT_P
+                    )
+                    .into());
+                }
+                match Self::scrypt_memory(*n, *r, *p) {
+                    Some(memory) if memory <= MAX_SCRYPT_MEMORY => {}
+                    _ => {
+                        return Err(
+                            format!("scrypt内存需求不能超过{}字节", MAX_SCRYPT_MEMORY).into()
+                        )
+                    }
+                }
+                if salt.is_empty() {
+                    return Err("盐值不能为空".into());
+                }
+            }
+        }
+        if params.dklen() == 0 || params.dklen() > MAX_DKLEN {
+            return Err(format!("派生密钥长度必须在1到{}字节之间", MAX_DKLEN).into());
+        }
+        Ok(())
+    }
+
+    /// scrypt 所需内存 128*r*(n+p) 字节，溢出时返回 `None`
+    /// Memory scrypt needs, 128*r*(n+p) bytes, or `None` on overflow
+    fn scrypt_memory(n: u64, r: u32, p: u32) -> Option<u64> {
+        n.checked_add(p as u64)?
+            .checked_mul(r as u64)?
+            .checked_mul(128)
+    }
+}
@@ -1,4 +1,15 @@
 pub mod aes_algorithm;
 pub mod base_58_algorithm;
 pub mod base_algorithm;
+pub mod bech32_algorithm;
+pub mod bip32_algorithm;
+pub mod des3_algorithm;
 pub mod ecdsa_algorithm;
+pub mod ecdsa_types;
+pub mod ecies_algorithm;
+pub mod ed25519_algorithm;
+pub mod kdf_algorithm;
+pub mod multisig_algorithm;
+pub mod schnorr_algorithm;
+pub mod signature_scheme;
+pub mod sm2_algorithm;
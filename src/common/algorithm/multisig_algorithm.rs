@@ -0,0 +1,228 @@
+/*
+ * M-of-N 多重签名工具类 / M-of-N Multi-signature Utilities
+ *
+ * 主要功能 / Main functionalities:
+ * 1. 由N个公钥构造赎回脚本 / Build a redeem script from N public keys
+ * 2. 赎回脚本的 P2SH 地址 / P2SH address of a redeem script
+ * 3. 按阈值验证一组签名 / Verify a set of signatures against the threshold
+ *
+ * 赎回脚本格式与比特币一致：OP_M <公钥1> ... <公钥N> OP_N OP_CHECKMULTISIG。
+ * 公钥顺序会影响脚本和地址，参与方须约定相同顺序。
+ * The redeem script matches Bitcoin: OP_M <pubkey 1> ... <pubkey N> OP_N OP_CHECKMULTISIG.
+ * Key order changes the script and the address, so all parties must agree on it.
+ */
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use crate::common::algorithm::ecdsa_algorithm::{ECDSAAlgorithm, EcdsaError};
+use crate::common::algorithm::ecdsa_types::PublicKey;
+use base64::{engine::general_purpose, Engine as _};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// 单个脚本支持的最大公钥数 / Maximum number of public keys in one script
+pub const MAX_PUBLIC_KEYS: usize = 16;
+/// P2SH 赎回脚本的最大长度 / Maximum size of a P2SH redeem script
+pub const MAX_REDEEM_SCRIPT_SIZE: usize = 520;
+/// OP_1，OP_N = OP_1 + N - 1 / OP_1, OP_N = OP_1 + N - 1
+const OP_1: u8 = 0x51;
+/// OP_CHECKMULTISIG
+const OP_CHECKMULTISIG: u8 = 0xae;
+
+/// 多重签名错误类型 / Multi-signature error type
+#[derive(Debug, Error)]
+pub enum MultisigError {
+    #[error("阈值必须在1到公钥数量之间 / Threshold must be between 1 and {keys}, got {threshold}")]
+    InvalidThreshold { threshold: usize, keys: usize },
+
+    #[error("公钥数量必须在1到16之间 / Public key count must be between 1 and 16, got {0}")]
+    InvalidKeyCount(usize),
+
+    #[error("公钥重复 / Duplicate public key at index {0}")]
+    DuplicatePublicKey(usize),
+
+    #[error("赎回脚本过长 / Redeem script is {0} bytes, limit is 520")]
+    ScriptTooLarge(usize),
+
+    #[error("赎回脚本格式错误 / Invalid redeem script: {0}")]
+    InvalidRedeemScript(&'static str),
+
+    #[error("Base64解码失败 / Base64 decode failed: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error(transparent)]
+    Ecdsa(#[from] EcdsaError),
+}
+
+pub struct MultisigAlgorithm;
+impl MultisigAlgorithm {
+    /// 构造BASE64编码的 M-of-N 赎回脚本 / Build a BASE64 encoded M-of-N redeem script
+    ///
+    /// 参数 / Parameters:
+    /// - threshold: 所需签名数M / Required signature count M
+    /// - public_keys: N个BASE64编码的公钥，压缩或非压缩均可 / N BASE64 public keys, compressed or not
+    pub fn create_redeem_script(
+        threshold: usize,
+        public_keys: &[&str],
+    ) -> Result<String, MultisigError> {
+        let keys = public_keys.len();
+        if keys == 0 || keys > MAX_PUBLIC_KEYS {
+            return Err(MultisigError::InvalidKeyCount(keys));
+        }
+        if threshold == 0 || threshold > keys {
+            return Err(MultisigError::InvalidThreshold { threshold, keys });
+        }
+
+        let mut seen = HashSet::new();
+        let mut script = vec![OP_1 + threshold as u8 - 1];
+        for (index, public_key) in public_keys.iter().enumerate() {
+            let public_key = PublicKey::from_base64(public_key)?;
+            Self::check_unique(&mut seen, &public_key, index)?;
+            let bytes = public_key.to_bytes();
+            // 33或65字节，直接以长度作为压栈操作码 / 33 or 65 bytes, pushed with a length opcode
+            script.push(bytes.len() as u8);
+            script.extend_from_slice(&bytes);
+        }
+        script.push(OP_1 + keys as u8 - 1);
+        script.push(OP_CHECKMULTISIG);
+
+        if script.len() > MAX_REDEEM_SCRIPT_SIZE {
+            return Err(MultisigError::ScriptTooLarge(script.len()));
+        }
+        Ok(general_purpose::STANDARD.encode(script))
+    }
+
+    /// 解析赎回脚本，返回阈值和BASE64编码的公钥 / Parse a redeem script into its threshold and BASE64 keys
+    pub fn parse_redeem_script(redeem_script: &str) -> Result<(usize, Vec<String>), MultisigError> {
+        let script = general_purpose::STANDARD.decode(redeem_script)?;
+        let (&last, rest) = script
+            .split_last()
+            .ok_or(MultisigError::InvalidRedeemScript("empty script"))?;
+        if last != OP_CHECKMULTISIG || rest.len() < 2 {
+            return Err(MultisigError::InvalidRedeemScript(
+                "missing OP_CHECKMULTISIG",
+            ));
+        }
+        let threshold = Self::small_int(rest[0])?;
+        let keys = Self::small_int(rest[rest.len() - 1])?;
+
+        let mut seen = HashSet::new();
+        let mut public_keys = Vec::with_capacity(keys);
+        let mut body = &rest[1..rest.len() - 1];
+        while let Some((&len, tail)) = body.split_first() {
+            let len = len as usize;
+            if (len != 33 && len != 65) || tail.len() < len {
+                return Err(MultisigError::InvalidRedeemScript("bad public key push"));
+            }
+            let public_key = PublicKey::from_bytes(&tail[..len])?;
+            Self::check_unique(&mut seen, &public_key, public_keys.len())?;
+            public_keys.push(public_key.to_base64());
+            body = &tail[len..];
+        }
+
+        if public_keys.len() != keys {
+            return Err(MultisigError::InvalidRedeemScript(
+                "key count does not match OP_N",
+            ));
+        }
+        if threshold > keys {
+            return Err(MultisigError::InvalidThreshold { threshold, keys });
+        }
+        Ok((threshold, public_keys))
+    }
+
+    /// 生成主网 P2SH 地址 / Generate a mainnet P2SH address
+    pub fn get_address(redeem_script: &str) -> Result<String, MultisigError> {
+        Self::get_address_with_version(redeem_script, Base58Version::P2sh)
+    }
+
+    /// 使用指定版本字节生成脚本哈希地址 / Generate a script hash address with a version byte
+    ///
+    /// 地址 = Base58Check(版本 || RIPEMD-160(SHA-256(赎回脚本)))
+    /// address = Base58Check(version || RIPEMD-160(SHA-256(redeem script)))
+    ///
+    /// 参数 / Parameters:
+    /// - redeem_script: BASE64编码的赎回脚本 / BASE64 encoded redeem script
+    /// - version: 通常为 `P2sh` 或 `TestnetP2sh` / Usually `P2sh` or `TestnetP2sh`
+    pub fn get_address_with_version(
+        redeem_script: &str,
+        version: Base58Version,
+    ) -> Result<String, MultisigError> {
+        // 先校验脚本，避免为任意数据生成地址 / Validate first so arbitrary bytes get no address
+        Self::parse_redeem_script(redeem_script)?;
+        let script = general_purpose::STANDARD.decode(redeem_script)?;
+        Ok(Base58Algorithm::encode_check(
+            version.to_byte(),
+            &ECDSAAlgorithm::hash160(&script),
+        ))
+    }
+
+    /// 按阈值验证多重签名 / Verify multi-signatures against the threshold
+    ///
+    /// 每个签名用 `ECDSAAlgorithm::verify_strict` 与尚未匹配的公钥逐一比对，同一公钥最多计数一次；
+    /// 至少有M个不同公钥的签名有效时返回 `true`。签名顺序不限。无法解析、高S值或非规范DER的签名
+    /// 不匹配任何公钥，因此审批签名无法被延展，多余的无效签名也不会否决有效的审批。
+    /// Each signature is checked with `ECDSAAlgorithm::verify_strict` against the keys not yet
+    /// matched, so a key counts at most once; returns `true` when signatures from at least M
+    /// distinct keys are valid. Signatures may be given in any order. A signature that fails to
+    /// parse, is high-S or is non-canonical DER matches no key, so approvals cannot be malleated
+    /// and an extra bad signature cannot veto a valid approval set.
+    ///
+    /// 参数 / Parameters:
+    /// - redeem_script: BASE64编码的赎回脚本 / BASE64 encoded redeem script
+    /// - data: 被签名的数据 / Signed data
+    /// - signatures: BASE64编码的DER签名 / BASE64 encoded DER signatures
+    pub fn verify(
+        redeem_script: &str,
+        data: &[u8],
+        signatures: &[&str],
+    ) -> Result<bool, MultisigError> {
+        let (threshold, public_keys) = Self::parse_redeem_script(redeem_script)?;
+        let mut matched = vec![false; public_keys.len()];
+        let mut valid = 0;
+
+        for signature in signatures {
+            for (index, public_key) in public_keys.iter().enumerate() {
+                if matched[index] {
+                    continue;
+                }
+                match ECDSAAlgorithm::verify_strict(public_key, data, signature) {
+                    Ok(true) => {
+                        matched[index] = true;
+                        valid += 1;
+                        break;
+                    }
+                    Ok(false) => {}
+                    // 公钥来自已解析的脚本，错误只可能由签名引起
+                    // Keys come from the parsed script, so an error is the signature's fault
+                    Err(_) => break,
+                }
+            }
+            if valid >= threshold {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 按曲线点去重，同一公钥的压缩与非压缩形式视为重复
+    /// Deduplicate by curve point, so the compressed and uncompressed forms of one key are duplicates
+    fn check_unique(
+        seen: &mut HashSet<Vec<u8>>,
+        public_key: &PublicKey,
+        index: usize,
+    ) -> Result<(), MultisigError> {
+        if seen.insert(public_key.with_compression(true).to_bytes()) {
+            Ok(())
+        } else {
+            Err(MultisigError::DuplicatePublicKey(index))
+        }
+    }
+
+    /// 解析 OP_1 到 OP_16 / Decode OP_1 through OP_16
+    fn small_int(opcode: u8) -> Result<usize, MultisigError> {
+        if (OP_1..OP_1 + MAX_PUBLIC_KEYS as u8).contains(&opcode) {
+            Ok((opcode - OP_1 + 1) as usize)
+        } else {
+            Err(MultisigError::InvalidRedeemScript("expected OP_1 to OP_16"))
+        }
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * Schnorr (BIP-340) 签名算法工具类 / Schnorr (BIP-340) Signature Algorithm Utilities
+ *
+ * 主要功能 / Main functionalities:
+ * 1. 生成SECP256K1私钥及32字节x-only公钥 / Generate SECP256K1 private keys and 32-byte x-only public keys
+ * 2. 带辅助随机数的BIP-340签名 / BIP-340 signing with auxiliary randomness
+ * 3. 单个签名验证与批量验证 / Single and batch signature verification
+ *
+ * 与 `ECDSAAlgorithm` 相同，密钥和签名均以BASE64字符串传递；`sign`/`verify` 先对数据计算
+ * SHA-256，`*_prehash` 系列直接使用32字节消息，与 BIP-340 测试向量一致。
+ * As with `ECDSAAlgorithm`, keys and signatures are passed as BASE64 strings; `sign`/`verify`
+ * hash the data with SHA-256 first, while the `*_prehash` family takes the 32-byte message
+ * directly, matching the BIP-340 test vectors.
+ */
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm, Hasher};
+use base64::{engine::general_purpose, Engine as _};
+use k256::{
+    elliptic_curve::{ops::Reduce, Field, PrimeField},
+    schnorr::{Signature, SigningKey, VerifyingKey},
+    FieldBytes, ProjectivePoint, Scalar, U256,
+};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use thiserror::Error;
+
+/// x-only 公钥长度 / x-only public key length
+pub const SCHNORR_PUBLIC_KEY_LEN: usize = 32;
+/// 签名长度：r(32) || s(32) / Signature length: r(32) || s(32)
+pub const SCHNORR_SIGNATURE_LEN: usize = 64;
+/// 消息及辅助随机数长度 / Message and auxiliary randomness length
+const MESSAGE_HASH_LEN: usize = 32;
+/// BIP-340 挑战值的标签 / Tag of the BIP-340 challenge hash
+const CHALLENGE_TAG: &[u8] = b"BIP0340/challenge";
+
+/// Schnorr 错误类型 / Schnorr error type
+#[derive(Debug, Error)]
+pub enum SchnorrError {
+    #[error("Base64解码失败 / Base64 decode failed: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error("密钥生成失败 / Key generation failed")]
+    KeyGenerationError,
+
+    #[error("公钥无效 / Invalid public key")]
+    InvalidPublicKey,
+
+    #[error("签名格式错误 / Invalid signature encoding")]
+    InvalidSignature,
+
+    #[error("签名失败 / Signing failed")]
+    SigningError,
+
+    #[error("消息哈希长度必须为32字节 / Message hash must be 32 bytes, got {0}")]
+    InvalidHashLength(usize),
+
+    #[error("辅助随机数长度必须为32字节 / Auxiliary randomness must be 32 bytes, got {0}")]
+    InvalidAuxRandLength(usize),
+}
+
+pub struct SchnorrAlgorithm;
+impl SchnorrAlgorithm {
+    /// 生成BASE64编码的私钥 / Generate BASE64 encoded private key
+    pub fn generate_private_key() -> String {
+        let signing_key = SigningKey::random(&mut OsRng);
+        general_purpose::STANDARD.encode(signing_key.to_bytes())
+    }
+
+    /// 从私钥生成32字节x-only公钥 / Generate the 32-byte x-only public key from a private key
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    pub fn generate_public_key(private_key: &str) -> Result<String, SchnorrError> {
+        let signing_key = Self::signing_key(private_key)?;
+        Ok(general_purpose::STANDARD.encode(signing_key.verifying_key().to_bytes()))
+    }
+
+    /// 生成Schnorr签名，辅助随机数取自系统随机源 / Generate a Schnorr signature with OS randomness
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - data: 待签名数据，签名前计算SHA-256 / Data to sign, hashed with SHA-256 first
+    pub fn sign(private_key: &str, data: &[u8]) -> Result<String, SchnorrError> {
+        let mut aux_rand = [0u8; MESSAGE_HASH_LEN];
+        OsRng.fill_bytes(&mut aux_rand);
+        Self::sign_prehash(
+            private_key,
+            &BaseAlgorithm::hash(HashAlgorithm::Sha256, data),
+            &aux_rand,
+        )
+    }
+
+    /// 使用指定辅助随机数对32字节消息签名 / Sign a 32-byte message with explicit auxiliary randomness
+    ///
+    /// 相同的私钥、消息和辅助随机数总是得到相同的签名
+    /// The same private key, message and auxiliary randomness always yield the same signature
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - message_hash: 32字节消息 / 32-byte message
+    /// - aux_rand: 32字节辅助随机数 / 32-byte auxiliary randomness
+    pub fn sign_prehash(
+        private_key: &str,
+        message_hash: &[u8],
+        aux_rand: &[u8],
+    ) -> Result<String, SchnorrError> {
+        let message_hash = Self::check_hash_len(message_hash)?;
+        let aux_rand: &[u8; MESSAGE_HASH_LEN] = aux_rand
+            .try_into()
+            .map_err(|_| SchnorrError::InvalidAuxRandLength(aux_rand.len()))?;
+        let signing_key = Self::signing_key(private_key)?;
+
+        let signature = signing_key
+            .try_sign_prehashed(message_hash, aux_rand)
+            .map_err(|_| SchnorrError::SigningError)?;
+        Ok(general_purpose::STANDARD.encode(signature.as_bytes()))
+    }
+
+    /// 验证Schnorr签名 / Verify a Schnorr signature
+    ///
+    /// Base64、长度或公钥错误返回 `Err`；r 不是有效的x-only点、s 不小于曲线阶等 BIP-340 验证失败
+    /// 返回 `Ok(false)`。
+    /// Base64, length or public key errors are `Err`; BIP-340 verification failures such as an
+    /// `r` that is not a valid x-only point or an `s` not below the curve order are `Ok(false)`.
+    ///
+    /// 参数 / Parameters:
+    /// - public_key: BASE64编码的x-only公钥 / BASE64 encoded x-only public key
+    /// - data: 原始数据，验证前计算SHA-256 / Original data, hashed with SHA-256 first
+    /// - signature: BASE64编码的64字节签名 / BASE64 encoded 64-byte signature
+    pub fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, SchnorrError> {
+        Self::verify_prehash(
+            public_key,
+            &BaseAlgorithm::hash(HashAlgorithm::Sha256, data),
+            signature,
+        )
+    }
+
+    /// 对32字节消息验证Schnorr签名 / Verify a Schnorr signature over a 32-byte message
+    pub fn verify_prehash(
+        public_key: &str,
+        message_hash: &[u8],
+        signature: &str,
+    ) -> Result<bool, SchnorrError> {
+        let message_hash = Self::check_hash_len(message_hash)?;
+        let verifying_key = Self::verifying_key(public_key)?;
+        Ok(match Self::signature(signature)? {
+            Some(signature) => verifying_key
+                .verify_prehashed(message_hash, &signature)
+                .is_ok(),
+            None => false,
+        })
+    }
+
+    /// 批量验证Schnorr签名，全部有效时返回 `true` / Batch verify Schnorr signatures
+    ///
+    /// 按 BIP-340 的批量验证算法，以随机系数将所有签名合并为一个等式校验；
+    /// 结果为 `false` 时无法得知具体哪一个签名无效，需逐个调用 `verify` 定位。
+    /// Follows the BIP-340 batch verification algorithm, folding all signatures into a single
+    /// equation with random coefficients; a `false` result does not say which signature failed,
+    /// call `verify` on each item to find it.
+    ///
+    /// 错误约定与 `verify` 相同：任一项的 Base64、长度或公钥错误返回 `Err`，任一项 r 或 s 越界
+    /// 返回 `Ok(false)`。空列表没有需要验证的签名，返回 `Ok(true)`。
+    /// Errors follow `verify`: a Base64, length or public key error in any item is `Err`, and an
+    /// out-of-range `r` or `s` in any item is `Ok(false)`. An empty list has nothing to verify
+    /// and returns `Ok(true)`.
+    ///
+    /// 参数 / Parameters:
+    /// - items: (BASE64公钥, 原始数据, BASE64签名) 列表 / List of (BASE64 public key, data, BASE64 signature)
+    pub fn batch_verify(items: &[(&str, &[u8], &str)]) -> Result<bool, SchnorrError> {
+        // (s1 + a2*s2 + ... + au*su)*G == R1 + a2*R2 + ... + au*Ru + e1*P1 + (a2*e2)*P2 + ... + (au*eu)*Pu
+        let mut lhs = Scalar::ZERO;
+        let mut rhs = ProjectivePoint::IDENTITY;
+        for (i, (public_key, data, signature)) in items.iter().enumerate() {
+            let verifying_key = Self::verifying_key(public_key)?;
+            let signature = match Self::signature(signature)? {
+                Some(signature) => signature,
+                None => return Ok(false),
+            };
+            let message_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, data);
+
+            let (r_bytes, s_bytes) = signature.as_bytes().split_at(32);
+            // r 必须是偶数y坐标点的x坐标 / r must be the x coordinate of a point with even y
+            let r_point: ProjectivePoint = match VerifyingKey::from_bytes(r_bytes) {
+                Ok(point) => ProjectivePoint::from(*point.as_affine()),
+                Err(_) => return Ok(false),
+            };
+            let s =
+                match Option::<Scalar>::from(Scalar::from_repr(*FieldBytes::from_slice(s_bytes))) {
+                    Some(s) => s,
+                    None => return Ok(false),
+                };
+
+            let mut challenge = Self::tagged_hasher(CHALLENGE_TAG);
+            challenge
+                .update(r_bytes)
+                .update(&verifying_key.to_bytes())
+                .update(&message_hash);
+            let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(FieldBytes::clone_from_slice(
+                &challenge.finalize(),
+            ));
+
+            let a = if i == 0 {
+                Scalar::ONE
+            } else {
+                Scalar::random(&mut OsRng)
+            };
+            let p_point = ProjectivePoint::from(*verifying_key.as_affine());
+            lhs += a * s;
+            rhs += r_point * a + p_point * (a * e);
+        }
+        Ok(ProjectivePoint::GENERATOR * lhs == rhs)
+    }
+
+    /// 解析私钥 / Parse a private key
+    fn signing_key(private_key: &str) -> Result<SigningKey, SchnorrError> {
+        let bytes = general_purpose::STANDARD.decode(private_key)?;
+        SigningKey::from_bytes(&bytes).map_err(|_| SchnorrError::KeyGenerationError)
+    }
+
+    /// 解析x-only公钥 / Parse an x-only public key
+    fn verifying_key(public_key: &str) -> Result<VerifyingKey, SchnorrError> {
+        let bytes = general_purpose::STANDARD.decode(public_key)?;
+        if bytes.len() != SCHNORR_PUBLIC_KEY_LEN {
+            return Err(SchnorrError::InvalidPublicKey);
+        }
+        VerifyingKey::from_bytes(&bytes).map_err(|_| SchnorrError::InvalidPublicKey)
+    }
+
+    /// 解析64字节签名，r 或 s 越界时返回 `None`
+    /// Parse a 64-byte signature, returning `None` if `r` or `s` is out of range
+    fn signature(signature: &str) -> Result<Option<Signature>, SchnorrError> {
+        let bytes = general_purpose::STANDARD.decode(signature)?;
+        if bytes.len() != SCHNORR_SIGNATURE_LEN {
+            return Err(SchnorrError::InvalidSignature);
+        }
+        Ok(Signature::try_from(bytes.as_slice()).ok())
+    }
+
+    /// 校验消息哈希长度 / Check the message hash length
+    fn check_hash_len(message_hash: &[u8]) -> Result<&[u8; MESSAGE_HASH_LEN], SchnorrError> {
+        message_hash
+            .try_into()
+            .map_err(|_| SchnorrError::InvalidHashLength(message_hash.len()))
+    }
+
+    /// BIP-340 标签哈希：SHA-256(SHA-256(tag) || SHA-256(tag) || ...)
+    /// BIP-340 tagged hash: SHA-256(SHA-256(tag) || SHA-256(tag) || ...)
+    fn tagged_hasher(tag: &[u8]) -> Hasher {
+        let tag_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, tag);
+        let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+        hasher.update(&tag_hash).update(&tag_hash);
+        hasher
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * 签名方案抽象 / Signature scheme abstraction
+ *
+ * 链上代码通过 `SignatureScheme` 对签名算法保持泛型，不再与 secp256k1 绑定。
+ * 所有实现沿用 `ECDSAAlgorithm` 的约定：私钥、公钥和签名均为BASE64字符串，地址为字符串。
+ * Chain code stays generic over the signing algorithm through `SignatureScheme` instead of
+ * being tied to secp256k1. Every implementation follows the `ECDSAAlgorithm` conventions:
+ * private keys, public keys and signatures are BASE64 strings, addresses are strings.
+ *
+ * 已实现 / Implementations:
+ * - `ECDSAAlgorithm`: secp256k1 ECDSA
+ * - `Ed25519Algorithm`: Ed25519
+ * - `Sm2Algorithm`: 国密 SM2 + SM3 / Chinese national standard SM2 with SM3
+ */
+use std::error::Error;
+
+/// 签名方案 / Signature scheme
+pub trait SignatureScheme {
+    /// 方案名称 / Scheme name
+    const NAME: &'static str;
+
+    /// 方案的错误类型 / Error type of the scheme
+    type Error: Error + Send + Sync + 'static;
+
+    /// 生成BASE64编码的私钥 / Generate a BASE64 encoded private key
+    fn generate_private_key() -> String;
+
+    /// 从私钥推导BASE64编码的公钥 / Derive the BASE64 encoded public key from a private key
+    fn derive_public_key(private_key: &str) -> Result<String, Self::Error>;
+
+    /// 对数据签名，返回BASE64编码的签名 / Sign data, returning a BASE64 encoded signature
+    fn sign(private_key: &str, data: &[u8]) -> Result<String, Self::Error>;
+
+    /// 验证签名，签名不匹配时返回 `Ok(false)` / Verify a signature, `Ok(false)` on mismatch
+    fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, Self::Error>;
+
+    /// 从公钥生成地址 / Generate an address from a public key
+    fn address(public_key: &str) -> Result<String, Self::Error>;
+}
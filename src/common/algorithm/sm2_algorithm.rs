@@ -0,0 +1,180 @@
+/*
+ * SM2 国密签名算法工具类 / SM2 (Chinese national standard) Signature Algorithm Utilities
+ *
+ * 主要功能 / Main functionalities:
+ * 1. 生成SM2曲线密钥对 / Generate SM2 curve key pairs
+ * 2. SM2 签名与验证，消息摘要使用 SM3 / SM2 signing and verification with SM3 digests
+ * 3. 仅使用 SM3 的地址生成与校验，使用 SM2 专用版本字节
+ *    Address generation and validation using SM3 only, under the SM2 version byte
+ *
+ * 签名前按 GB/T 32918 计算 Z = SM3(ENTL || ID || a || b || xG || yG || xA || yA)，
+ * 未指定用户标识时使用标准默认值 "1234567812345678"。
+ * Before signing, Z = SM3(ENTL || ID || a || b || xG || yG || xA || yA) is computed per
+ * GB/T 32918; the standard default "1234567812345678" is used when no user ID is given.
+ */
+use crate::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use crate::common::algorithm::signature_scheme::SignatureScheme;
+use base64::{engine::general_purpose, Engine as _};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sm2::dsa::{
+    signature::{Signer, Verifier},
+    Signature, SigningKey, VerifyingKey,
+};
+use thiserror::Error;
+
+/// GM/T 0009 规定的默认用户标识 / Default user ID defined by GM/T 0009
+pub const SM2_DEFAULT_ID: &str = "1234567812345678";
+/// 私钥长度 / Private key length
+const PRIVATE_KEY_LEN: usize = 32;
+/// 地址中公钥摘要的长度 / Length of the public key digest in an address
+const ADDRESS_HASH_LEN: usize = 20;
+
+/// SM2 错误类型 / SM2 error type
+#[derive(Debug, Error)]
+pub enum Sm2Error {
+    #[error("Base64解码失败 / Base64 decode failed: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error("密钥生成失败 / Key generation failed")]
+    KeyGenerationError,
+
+    #[error("公钥无效 / Invalid public key")]
+    InvalidPublicKey,
+
+    #[error("签名格式错误 / Invalid signature encoding")]
+    InvalidSignature,
+
+    #[error("签名失败 / Signing failed")]
+    SigningError,
+
+    #[error("地址错误 / Address error: {0}")]
+    AddressError(String),
+}
+
+pub struct Sm2Algorithm;
+impl Sm2Algorithm {
+    /// 生成BASE64编码的私钥 / Generate BASE64 encoded private key
+    pub fn generate_private_key() -> String {
+        let mut secret = [0u8; PRIVATE_KEY_LEN];
+        // 随机数落在 [1, n-1] 之外的概率可忽略，失败时重新生成
+        // Values outside [1, n-1] are vanishingly rare; draw again if one comes up
+        loop {
+            OsRng.fill_bytes(&mut secret);
+            if SigningKey::from_slice(SM2_DEFAULT_ID, &secret).is_ok() {
+                return general_purpose::STANDARD.encode(secret);
+            }
+        }
+    }
+
+    /// 从私钥生成65字节非压缩公钥 / Generate the 65-byte uncompressed public key from a private key
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    pub fn generate_public_key(private_key: &str) -> Result<String, Sm2Error> {
+        let signing_key = Self::signing_key(private_key, SM2_DEFAULT_ID)?;
+        Ok(general_purpose::STANDARD.encode(signing_key.verifying_key().to_sec1_bytes()))
+    }
+
+    /// 生成地址：取 SM3(公钥) 的前20字节，以 `Base58Version::Sm2` 版本字节（以 "S" 开头）做
+    /// Base58Check 编码
+    /// Generate an address: Base58Check of the first 20 bytes of SM3(public key) under the
+    /// `Base58Version::Sm2` version byte (starting with "S")
+    pub fn get_address(public_key: &str) -> Result<String, Sm2Error> {
+        let verifying_key = Self::verifying_key(public_key, SM2_DEFAULT_ID)?;
+        let hash = BaseAlgorithm::hash(HashAlgorithm::Sm3, &verifying_key.to_sec1_bytes());
+        Ok(Base58Algorithm::encode_check(
+            Base58Version::Sm2.to_byte(),
+            &hash[..ADDRESS_HASH_LEN],
+        ))
+    }
+
+    /// 校验 SM2 地址并返回其公钥摘要 / Validate an SM2 address and return its public key digest
+    ///
+    /// 其他签名方案的地址因版本字节不符返回 `AddressError`
+    /// Addresses of other signature schemes fail with `AddressError` on the version byte
+    pub fn validate_address(address: &str) -> Result<Vec<u8>, Sm2Error> {
+        Base58Algorithm::validate_address(address, Base58Version::Sm2)
+            .map_err(|e| Sm2Error::AddressError(e.to_string()))
+    }
+
+    /// 使用默认用户标识生成SM2签名 / Generate an SM2 signature with the default user ID
+    pub fn sign(private_key: &str, data: &[u8]) -> Result<String, Sm2Error> {
+        Self::sign_with_id(private_key, SM2_DEFAULT_ID, data)
+    }
+
+    /// 使用指定用户标识生成SM2签名 / Generate an SM2 signature with the given user ID
+    ///
+    /// 返回BASE64编码的64字节签名 r || s
+    /// Returns the BASE64 encoded 64-byte signature r || s
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - user_id: 用户标识 / User ID
+    /// - data: 待签名数据 / Data to sign
+    pub fn sign_with_id(private_key: &str, user_id: &str, data: &[u8]) -> Result<String, Sm2Error> {
+        let signing_key = Self::signing_key(private_key, user_id)?;
+        let signature: Signature = signing_key
+            .try_sign(data)
+            .map_err(|_| Sm2Error::SigningError)?;
+        Ok(general_purpose::STANDARD.encode(signature.to_bytes()))
+    }
+
+    /// 使用默认用户标识验证SM2签名 / Verify an SM2 signature with the default user ID
+    pub fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, Sm2Error> {
+        Self::verify_with_id(public_key, SM2_DEFAULT_ID, data, signature)
+    }
+
+    /// 使用指定用户标识验证SM2签名 / Verify an SM2 signature with the given user ID
+    pub fn verify_with_id(
+        public_key: &str,
+        user_id: &str,
+        data: &[u8],
+        signature: &str,
+    ) -> Result<bool, Sm2Error> {
+        let verifying_key = Self::verifying_key(public_key, user_id)?;
+        let sig_bytes = general_purpose::STANDARD.decode(signature)?;
+        let signature =
+            Signature::from_slice(&sig_bytes).map_err(|_| Sm2Error::InvalidSignature)?;
+        Ok(verifying_key.verify(data, &signature).is_ok())
+    }
+
+    /// 解析私钥 / Parse a private key
+    fn signing_key(private_key: &str, user_id: &str) -> Result<SigningKey, Sm2Error> {
+        let bytes = general_purpose::STANDARD.decode(private_key)?;
+        SigningKey::from_slice(user_id, &bytes).map_err(|_| Sm2Error::KeyGenerationError)
+    }
+
+    /// 解析SEC1编码的公钥 / Parse a SEC1 encoded public key
+    fn verifying_key(public_key: &str, user_id: &str) -> Result<VerifyingKey, Sm2Error> {
+        let bytes = general_purpose::STANDARD.decode(public_key)?;
+        VerifyingKey::from_sec1_bytes(user_id, &bytes).map_err(|_| Sm2Error::InvalidPublicKey)
+    }
+}
+
+impl SignatureScheme for Sm2Algorithm {
+    const NAME: &'static str = "sm2-sm3";
+
+    type Error = Sm2Error;
+
+    fn generate_private_key() -> String {
+        Sm2Algorithm::generate_private_key()
+    }
+
+    fn derive_public_key(private_key: &str) -> Result<String, Sm2Error> {
+        Sm2Algorithm::generate_public_key(private_key)
+    }
+
+    fn sign(private_key: &str, data: &[u8]) -> Result<String, Sm2Error> {
+        Sm2Algorithm::sign(private_key, data)
+    }
+
+    fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<bool, Sm2Error> {
+        Sm2Algorithm::verify(public_key, data, signature)
+    }
+
+    fn address(public_key: &str) -> Result<String, Sm2Error> {
+        Sm2Algorithm::get_address(public_key)
+    }
+}
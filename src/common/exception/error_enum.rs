@@ -1,3 +1,6 @@
+use std::error::Error;
+use std::fmt;
+
 /// ErrorNum 枚举定义了一系列错误编号和对应的错误消息，用于表示不同类型的错误。
 ///
 /// The ErrorNum enum defines a series of error codes and corresponding error messages
@@ -61,3 +64,13 @@ impl ErrorNum {
         }
     }
 }
+
+/// 以 "[错误编号] 错误消息" 的格式输出错误
+/// Display the error as "[code] message"
+impl fmt::Display for ErrorNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.get_ret_code(), self.get_ret_msg())
+    }
+}
+
+impl Error for ErrorNum {}
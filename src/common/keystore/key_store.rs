@@ -0,0 +1,316 @@
+/*
+ * 加密 keystore 文件格式 / Encrypted keystore file format
+ *
+ * 将 SECP256K1 私钥用口令派生密钥加密后保存为带版本号的 JSON 文件。字段布局借鉴以太坊
+ * keystore v3，但这是本项目自己的格式（版本1）：MAC 使用 SHA-256 而非 Keccak-256，地址为
+ * Base58Check，因此以太坊工具无法读取，本模块也不接受以太坊 keystore。
+ * The SECP256K1 private key is encrypted under a passphrase-derived key and stored as a
+ * versioned JSON file. The field layout borrows from the Ethereum keystore v3, but this is the
+ * project's own format (version 1): the MAC uses SHA-256 rather than Keccak-256 and the address
+ * is Base58Check, so Ethereum tools cannot read it and this module rejects Ethereum keystores.
+ *
+ * 派生密钥的前16字节作为 AES-128-CTR 加密密钥，后16字节与密文拼接后计算 SHA-256 作为 MAC，
+ * 解密前校验。MAC 不匹配可能是口令错误，也可能是密文或 MAC 被改动，两者无法区分，
+ * 统一返回 `InvalidPassphrase`。
+ * The first 16 bytes of the derived key are the AES-128-CTR key; the last 16 bytes are
+ * concatenated with the ciphertext and hashed with SHA-256 to form the MAC, checked before
+ * decrypting. A MAC mismatch means either a wrong passphrase or an edited ciphertext or MAC;
+ * the two cannot be told apart, so both yield `InvalidPassphrase`.
+ *
+ * KDF 参数来自文件，解密前按 `KDFAlgorithm::check_params` 的上限校验，防止构造的文件耗尽资源。
+ * KDF parameters come from the file and are checked against the limits of
+ * `KDFAlgorithm::check_params` before decrypting, so a crafted file cannot exhaust resources.
+ *
+ * 派生密钥和解密出的私钥在释放时清零；Unix 上保存的文件权限为 0600。
+ * The derived key and the decrypted private key are zeroized on drop; on Unix the saved file
+ * has mode 0600.
+ */
+use crate::common::algorithm::aes_algorithm::{AESAlgorithm, AesCiphertext, AesMode};
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use crate::common::algorithm::ecdsa_algorithm::{ECDSAAlgorithm, EcdsaError};
+use crate::common::algorithm::kdf_algorithm::{KDFAlgorithm, KdfParams};
+use base64::{engine::general_purpose, Engine as _};
+use openssl::memcmp;
+use openssl::rand::rand_bytes;
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// 当前 keystore 格式版本，与以太坊 keystore 的版本号无关
+/// Current keystore format version, unrelated to Ethereum keystore versions
+pub const KEYSTORE_VERSION: u32 = 1;
+/// keystore 使用的对称加密算法
+/// Symmetric cipher used by the keystore
+const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+/// 派生密钥长度，前半部分用于加密，后半部分用于 MAC
+/// Derived key length; the first half encrypts, the second half keys the MAC
+const DERIVED_KEY_LEN: usize = 32;
+
+/// keystore 错误类型 / Keystore error type
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    /// MAC 不匹配：口令错误，或密文/MAC 被改动
+    /// MAC mismatch: wrong passphrase, or an edited ciphertext/MAC
+    #[error("口令错误或文件被改动 / Invalid passphrase or tampered keystore")]
+    InvalidPassphrase,
+
+    #[error("keystore 文件损坏 / Corrupted keystore: {0}")]
+    Corrupted(String),
+
+    #[error("不支持的 keystore 版本 / Unsupported keystore version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("加密失败 / Encryption failed: {0}")]
+    EncryptionFailed(String),
+
+    #[error("文件读写失败 / File IO failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("私钥无效 / Invalid private key: {0}")]
+    InvalidKey(#[from] EcdsaError),
+}
+
+/// 对称加密参数 / Cipher parameters
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CipherParams {
+    /// AES-CTR 初始计数器 / AES-CTR initial counter block
+    #[serde_as(as = "Hex")]
+    pub iv: Vec<u8>,
+}
+
+/// keystore 加密部分 / Crypto section of the keystore
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    /// 对称加密算法名称 / Cipher name
+    pub cipher: String,
+    /// 对称加密参数 / Cipher parameters
+    pub cipherparams: CipherParams,
+    /// 加密后的私钥 / Encrypted private key
+    #[serde_as(as = "Hex")]
+    pub ciphertext: Vec<u8>,
+    /// 密钥派生参数 / Key derivation parameters
+    #[serde(flatten)]
+    pub kdf: KdfParams,
+    /// SHA-256(派生密钥后16字节 || 密文) / SHA-256(derived key[16..32] || ciphertext)
+    #[serde_as(as = "Hex")]
+    pub mac: Vec<u8>,
+}
+
+/// 加密 keystore 文件 / Encrypted keystore file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyStore {
+    /// 格式版本 / Format version
+    pub version: u32,
+    /// 随机 UUID / Random UUID
+    pub id: String,
+    /// 私钥对应的地址 / Address of the private key
+    pub address: String,
+    /// 加密部分 / Crypto section
+    pub crypto: KeystoreCrypto,
+}
+
+impl KeyStore {
+    /// 使用口令加密私钥 / Encrypt a private key with a passphrase
+    ///
+    /// 参数 / Parameters:
+    /// - private_key: BASE64编码的私钥 / BASE64 encoded private key
+    /// - passphrase: 口令 / Passphrase
+    /// - kdf: 密钥派生参数，派生长度必须为32字节 / KDF parameters, dklen must be 32
+    pub fn encrypt(
+        private_key: &str,
+        passphrase: &str,
+        kdf: KdfParams,
+    ) -> Result<Self, KeystoreError> {
+        let public_key = ECDSAAlgorithm::generate_public_key(private_key, true)?;
+        let address = ECDSAAlgorithm::get_address(&public_key)?;
+        let key_bytes = Zeroizing::new(
+            general_purpose::STANDARD
+                .decode(private_key)
+                .map_err(EcdsaError::from)?,
+        );
+
+        if kdf.dklen() != DERIVED_KEY_LEN {
+            return Err(KeystoreError::EncryptionFailed(format!(
+                "派生密钥长度必须为{}字节 / dklen must be {}",
+                DERIVED_KEY_LEN, DERIVED_KEY_LEN
+            )));
+        }
+        let derived_key = Zeroizing::new(
+            KDFAlgorithm::derive_key(passphrase.as_bytes(), &kdf)
+                .map_err(|e| KeystoreError::EncryptionFailed(e.to_string()))?,
+        );
+        let envelope = AESAlgorithm::encrypt(AesMode::Ctr, &derived_key[..16], &key_bytes, None)
+            .map_err(|e| KeystoreError::EncryptionFailed(e.to_string()))?;
+        let mac = Self::compute_mac(&derived_key, &envelope.ciphertext);
+
+        Ok(KeyStore {
+            version: KEYSTORE_VERSION,
+            id: Self::random_uuid()?,
+            address,
+            crypto: KeystoreCrypto {
+                cipher: KEYSTORE_CIPHER.to_string(),
+                cipherparams: CipherParams { iv: envelope.iv },
+                ciphertext: envelope.ciphertext,
+                kdf,
+                mac,
+            },
+        })
+    }
+
+    /// 使用口令解密私钥 / Decrypt the private key with a passphrase
+    ///
+    /// 返回BASE64编码的私钥，释放时清零；MAC 不匹配（口令错误或密文/MAC 被改动）时返回 `InvalidPassphrase`，
+    /// 其余结构或内容错误返回 `Corrupted`。
+    /// Returns the BASE64 encoded private key, zeroized on drop; a MAC mismatch (wrong passphrase, or an edited
+    /// ciphertext or MAC) yields `InvalidPassphrase`, any other structural or content error
+    /// yields `Corrupted`.
+    pub fn decrypt(&self, passphrase: &str) -> Result<Zeroizing<String>, KeystoreError> {
+        self.check_format()?;
+        let derived_key = Zeroizing::new(
+            KDFAlgorithm::derive_key(passphrase.as_bytes(), &self.crypto.kdf)
+                .map_err(|e| KeystoreError::Corrupted(e.to_string()))?,
+        );
+        let mac = Self::compute_mac(&derived_key, &self.crypto.ciphertext);
+        if !memcmp::eq(&mac, &self.crypto.mac) {
+            return Err(KeystoreError::InvalidPassphrase);
+        }
+
+        let envelope = AesCiphertext {
+            mode: AesMode::Ctr,
+            iv: self.crypto.cipherparams.iv.clone(),
+            tag: Vec::new(),
+            ciphertext: self.crypto.ciphertext.clone(),
+        };
+        let key_bytes = Zeroizing::new(
+            AESAlgorithm::decrypt(&derived_key[..16], &envelope, None)
+                .map_err(|e| KeystoreError::Corrupted(e.to_string()))?,
+        );
+        let private_key = Zeroizing::new(general_purpose::STANDARD.encode(&*key_bytes));
+
+        // MAC 通过但地址不符，说明文件内容被改动 / MAC passed but address differs: file was edited
+        let address = ECDSAAlgorithm::generate_public_key(&private_key, true)
+            .and_then(|public_key| ECDSAAlgorithm::get_address(&public_key))
+            .map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        if address != self.address {
+            return Err(KeystoreError::Corrupted(
+                "地址与私钥不匹配 / address does not match the private key".to_string(),
+            ));
+        }
+        Ok(private_key)
+    }
+
+    /// 修改口令，返回使用新口令和新参数重新加密的 keystore
+    /// Change the passphrase, returning a keystore re-encrypted under the new passphrase
+    ///
+    /// 参数 / Parameters:
+    /// - old_passphrase: 原口令 / Current passphrase
+    /// - new_passphrase: 新口令 / New passphrase
+    /// - kdf: 新的密钥派生参数 / New KDF parameters
+    pub fn change_passphrase(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+        kdf: KdfParams,
+    ) -> Result<Self, KeystoreError> {
+        let private_key = self.decrypt(old_passphrase)?;
+        let mut keystore = Self::encrypt(&private_key, new_passphrase, kdf)?;
+        keystore.id = self.id.clone();
+        Ok(keystore)
+    }
+
+    /// 序列化为 JSON 字符串 / Serialise to a JSON string
+    pub fn to_json(&self) -> Result<String, KeystoreError> {
+        serde_json::to_string_pretty(self).map_err(|e| KeystoreError::Corrupted(e.to_string()))
+    }
+
+    /// 从 JSON 字符串解析并校验格式 / Parse from a JSON string and check the format
+    pub fn from_json(json: &str) -> Result<Self, KeystoreError> {
+        let keystore: KeyStore =
+            serde_json::from_str(json).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        keystore.check_format()?;
+        Ok(keystore)
+    }
+
+    /// 保存到文件，Unix 上文件权限为 0600 / Save to a file, with mode 0600 on Unix
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), KeystoreError> {
+        let json = self.to_json()?;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        // 覆盖已有文件时同样收紧权限，写入私钥密文之前完成
+        // Tighten the mode when overwriting an existing file too, before writing the ciphertext
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+
+    /// 从文件加载 / Load from a file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, KeystoreError> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// 校验版本、算法及字段长度 / Check the version, cipher and field lengths
+    fn check_format(&self) -> Result<(), KeystoreError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+        if self.crypto.cipher != KEYSTORE_CIPHER {
+            return Err(KeystoreError::Corrupted(format!(
+                "不支持的加密算法 / unsupported cipher: {}",
+                self.crypto.cipher
+            )));
+        }
+        KDFAlgorithm::check_params(&self.crypto.kdf)
+            .map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        if self.crypto.kdf.dklen() != DERIVED_KEY_LEN {
+            return Err(KeystoreError::Corrupted(format!(
+                "派生密钥长度必须为{}字节 / dklen must be {}",
+                DERIVED_KEY_LEN, DERIVED_KEY_LEN
+            )));
+        }
+        if self.crypto.cipherparams.iv.len() != AesMode::Ctr.iv_len() {
+            return Err(KeystoreError::Corrupted(
+                "IV长度错误 / invalid iv length".to_string(),
+            ));
+        }
+        if self.crypto.mac.len() != 32 {
+            return Err(KeystoreError::Corrupted(
+                "MAC长度错误 / invalid mac length".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 计算 MAC / Compute the MAC
+    fn compute_mac(derived_key: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+        let mut data = derived_key[16..DERIVED_KEY_LEN].to_vec();
+        data.extend_from_slice(ciphertext);
+        BaseAlgorithm::hash(HashAlgorithm::Sha256, &data)
+    }
+
+    /// 生成随机 UUID v4 / Generate a random UUID v4
+    fn random_uuid() -> Result<String, KeystoreError> {
+        let mut bytes = [0u8; 16];
+        rand_bytes(&mut bytes).map_err(|e| KeystoreError::EncryptionFailed(e.to_string()))?;
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        ))
+    }
+}
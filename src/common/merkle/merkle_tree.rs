@@ -0,0 +1,279 @@
+/*
+ * 默克尔树与包含证明 / Merkle tree with inclusion proofs
+ *
+ * 区块用默克尔根承诺其中的交易，轻客户端只需根和一条证明路径即可确认某笔交易在区块中，
+ * 无需下载整个区块。节点哈希使用 `BaseAlgorithm::hash_twice`（即 `encode_twice`）。
+ * A block commits to its transactions through the Merkle root; a light client checks that a
+ * transaction is in the block from the root and one proof path, without downloading the block.
+ * Node hashes use `BaseAlgorithm::hash_twice` (the typed form of `encode_twice`).
+ *
+ * 两种模式 / Two modes:
+ * - `Bitcoin`：节点 = H(左 || 右)，层内节点数为奇数时复制最后一个节点，与比特币区块头一致。
+ *   叶子列表末尾重复的交易会得到相同的根（CVE-2012-2459），需要调用方自行拒绝重复叶子。
+ *   `Bitcoin`: node = H(left || right) and the last node is duplicated on odd levels, matching
+ *   Bitcoin block headers. A leaf list with trailing duplicates yields the same root
+ *   (CVE-2012-2459), so callers must reject duplicate leaves themselves.
+ * - `DomainSeparated`：叶子 = H(0x00 || 数据)，节点 = H(0x01 || 左 || 右)，奇数节点直接提升到上一层，
+ *   叶子与内部节点无法互相伪造（防第二原像攻击），也不存在复制带来的歧义。
+ *   `DomainSeparated`: leaf = H(0x00 || data), node = H(0x01 || left || right) and an odd node is
+ *   promoted unchanged, so leaves and inner nodes cannot stand in for each other (second-preimage
+ *   safe) and duplication ambiguity is gone.
+ */
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use thiserror::Error;
+
+/// 叶子哈希的域分隔前缀 / Domain separation prefix of leaf hashes
+const LEAF_PREFIX: u8 = 0x00;
+/// 内部节点哈希的域分隔前缀 / Domain separation prefix of inner node hashes
+const NODE_PREFIX: u8 = 0x01;
+
+/// 默克尔树错误类型 / Merkle tree error type
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MerkleError {
+    #[error("默克尔树至少需要一个叶子 / A Merkle tree needs at least one leaf")]
+    EmptyTree,
+
+    #[error("叶子索引越界 / Leaf index {index} out of range for {len} leaves")]
+    IndexOutOfRange { index: usize, len: usize },
+}
+
+/// 树的哈希模式 / Hashing mode of the tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MerkleMode {
+    /// 比特币兼容，奇数层复制最后一个节点 / Bitcoin compatible, duplicates the last odd node
+    Bitcoin,
+    /// 叶子/节点域分隔，防第二原像攻击 / Leaf/node domain separation, second-preimage safe
+    DomainSeparated,
+}
+
+/// 兄弟节点相对于当前节点的位置 / Position of the sibling relative to the current node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// 证明路径中的一步 / One step of a proof path
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofStep {
+    /// 兄弟节点哈希 / Sibling hash
+    #[serde_as(as = "Hex")]
+    pub hash: Vec<u8>,
+    /// 兄弟节点位置 / Sibling position
+    pub side: Side,
+}
+
+/// 包含证明：从叶子到根的兄弟节点路径 / Inclusion proof: sibling path from a leaf to the root
+///
+/// JSON 序列化时哈希为十六进制字符串，便于发送给轻客户端
+/// Hashes are hex strings in JSON so the proof can be sent to light clients
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// 叶子索引 / Leaf index
+    pub leaf_index: usize,
+    /// 自底向上的路径，提升的奇数节点没有对应步骤 / Bottom-up path; promoted odd nodes add no step
+    pub path: Vec<ProofStep>,
+}
+
+impl MerkleProof {
+    /// 由叶子哈希沿路径计算根 / Compute the root from a leaf hash along the path
+    pub fn compute_root(
+        &self,
+        algorithm: HashAlgorithm,
+        mode: MerkleMode,
+        leaf_hash: &[u8],
+    ) -> Vec<u8> {
+        self.path
+            .iter()
+            .fold(leaf_hash.to_vec(), |current, step| match step.side {
+                Side::Left => MerkleTree::hash_node(algorithm, mode, &step.hash, &current),
+                Side::Right => MerkleTree::hash_node(algorithm, mode, &current, &step.hash),
+            })
+    }
+
+    /// 验证叶子哈希是否包含在给定根下 / Verify that a leaf hash is included under the root
+    ///
+    /// # 参数
+    /// * `algorithm`: 构建树时使用的哈希算法
+    /// * `mode`: 构建树时使用的模式
+    /// * `leaf_hash`: 叶子哈希，可由 `MerkleTree::hash_leaf` 计算
+    /// * `root`: 可信的默克尔根，如区块头中的值
+    /// # Parameters
+    /// * `algorithm`: Hash algorithm the tree was built with
+    /// * `mode`: Mode the tree was built with
+    /// * `leaf_hash`: Leaf hash, see `MerkleTree::hash_leaf`
+    /// * `root`: Trusted Merkle root, such as the value in a block header
+    pub fn verify(
+        &self,
+        algorithm: HashAlgorithm,
+        mode: MerkleMode,
+        leaf_hash: &[u8],
+        root: &[u8],
+    ) -> bool {
+        self.compute_root(algorithm, mode, leaf_hash) == root
+    }
+}
+
+/// 默克尔树，保存每一层的节点哈希 / Merkle tree keeping the node hashes of every level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    algorithm: HashAlgorithm,
+    mode: MerkleMode,
+    /// levels[0] 为叶子，最后一层只有根 / levels[0] are the leaves, the last level is the root
+    levels: Vec<Vec<Vec<u8>>>,
+}
+
+impl MerkleTree {
+    /// 由已计算好的叶子哈希构建，如比特币的交易ID
+    /// Build from precomputed leaf hashes, such as Bitcoin transaction IDs
+    ///
+    /// # 参数
+    /// * `algorithm`: 哈希算法，比特币为 SHA-256（两次）
+    /// * `mode`: 哈希模式
+    /// * `leaf_hashes`: 叶子哈希，按顺序排列
+    /// # Parameters
+    /// * `algorithm`: Hash algorithm, SHA-256 (applied twice) for Bitcoin
+    /// * `mode`: Hashing mode
+    /// * `leaf_hashes`: Leaf hashes in order
+    pub fn new(
+        algorithm: HashAlgorithm,
+        mode: MerkleMode,
+        leaf_hashes: Vec<Vec<u8>>,
+    ) -> Result<Self, MerkleError> {
+        if leaf_hashes.is_empty() {
+            return Err(MerkleError::EmptyTree);
+        }
+
+        let mut levels = vec![leaf_hashes];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = &levels[levels.len() - 1];
+            let next = level
+                .chunks(2)
+                .map(|pair| match (pair, mode) {
+                    ([left, right], _) => Self::hash_node(algorithm, mode, left, right),
+                    ([odd], MerkleMode::Bitcoin) => Self::hash_node(algorithm, mode, odd, odd),
+                    ([odd], MerkleMode::DomainSeparated) => odd.clone(),
+                    _ => unreachable!("chunks(2) yields one or two nodes"),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Ok(MerkleTree {
+            algorithm,
+            mode,
+            levels,
+        })
+    }
+
+    /// 由原始数据构建，每项先用 `hash_leaf` 计算叶子哈希
+    /// Build from raw data, hashing each item with `hash_leaf` first
+    pub fn from_data<T: AsRef<[u8]>>(
+        algorithm: HashAlgorithm,
+        mode: MerkleMode,
+        items: &[T],
+    ) -> Result<Self, MerkleError> {
+        let leaf_hashes = items
+            .iter()
+            .map(|item| Self::hash_leaf(algorithm, mode, item.as_ref()))
+            .collect();
+        Self::new(algorithm, mode, leaf_hashes)
+    }
+
+    /// 计算叶子哈希：比特币模式为 H(数据)，域分隔模式为 H(0x00 || 数据)
+    /// Compute a leaf hash: H(data) in Bitcoin mode, H(0x00 || data) in domain-separated mode
+    pub fn hash_leaf(algorithm: HashAlgorithm, mode: MerkleMode, data: &[u8]) -> Vec<u8> {
+        match mode {
+            MerkleMode::Bitcoin => BaseAlgorithm::hash_twice(algorithm, data),
+            MerkleMode::DomainSeparated => {
+                let mut input = Vec::with_capacity(1 + data.len());
+                input.push(LEAF_PREFIX);
+                input.extend_from_slice(data);
+                BaseAlgorithm::hash_twice(algorithm, &input)
+            }
+        }
+    }
+
+    /// 计算内部节点哈希 / Compute an inner node hash
+    pub fn hash_node(
+        algorithm: HashAlgorithm,
+        mode: MerkleMode,
+        left: &[u8],
+        right: &[u8],
+    ) -> Vec<u8> {
+        let mut input = Vec::with_capacity(1 + left.len() + right.len());
+        if mode == MerkleMode::DomainSeparated {
+            input.push(NODE_PREFIX);
+        }
+        input.extend_from_slice(left);
+        input.extend_from_slice(right);
+        BaseAlgorithm::hash_twice(algorithm, &input)
+    }
+
+    /// 默克尔根 / Merkle root
+    pub fn root(&self) -> &[u8] {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// 叶子数量 / Number of leaves
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// 指定索引的叶子哈希 / Leaf hash at an index
+    pub fn leaf(&self, index: usize) -> Option<&[u8]> {
+        self.levels[0].get(index).map(Vec::as_slice)
+    }
+
+    /// 哈希算法 / Hash algorithm
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// 哈希模式 / Hashing mode
+    pub fn mode(&self) -> MerkleMode {
+        self.mode
+    }
+
+    /// 生成叶子的包含证明 / Generate the inclusion proof of a leaf
+    pub fn proof(&self, leaf_index: usize) -> Result<MerkleProof, MerkleError> {
+        if leaf_index >= self.leaf_count() {
+            return Err(MerkleError::IndexOutOfRange {
+                index: leaf_index,
+                len: self.leaf_count(),
+            });
+        }
+
+        let mut path = Vec::with_capacity(self.levels.len() - 1);
+        let mut index = leaf_index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                path.push(ProofStep {
+                    hash: level[sibling].clone(),
+                    side: if sibling < index {
+                        Side::Left
+                    } else {
+                        Side::Right
+                    },
+                });
+            } else if self.mode == MerkleMode::Bitcoin {
+                // 奇数层的最后一个节点与自身配对 / The last odd node pairs with itself
+                path.push(ProofStep {
+                    hash: level[index].clone(),
+                    side: Side::Right,
+                });
+            }
+            index /= 2;
+        }
+        Ok(MerkleProof { leaf_index, path })
+    }
+
+    /// 用本树的算法和模式验证证明 / Verify a proof with this tree's algorithm and mode
+    pub fn verify(&self, leaf_hash: &[u8], proof: &MerkleProof) -> bool {
+        proof.verify(self.algorithm, self.mode, leaf_hash, self.root())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod merkle_tree;
+pub mod sparse_merkle_tree;
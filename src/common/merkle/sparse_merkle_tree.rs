@@ -0,0 +1,399 @@
+/*
+ * 稀疏默克尔树 / Sparse Merkle tree
+ *
+ * 账户模型的世界状态承诺：键经哈希后作为从根到叶子的路径，区块以 `root_hash` 作为 `state_root`。
+ * 支持读写删除、包含证明与不包含证明，哈希由 `BaseAlgorithm` 提供。
+ * World-state commitment for the account model: a hashed key is the path from the root to its
+ * leaf and blocks commit to `root_hash` as their `state_root`. Supports get/put/delete and
+ * inclusion and non-inclusion proofs, with hashing provided by `BaseAlgorithm`.
+ *
+ * 树是压缩的：只有一个叶子的子树直接由该叶子表示，空子树的哈希为全零，因此深度只与键数量的对数相关。
+ * 根只取决于当前的键值集合，与插入和删除的顺序无关。
+ * The tree is compacted: a subtree holding a single leaf is that leaf and an empty subtree hashes
+ * to all zeros, so depth grows with the log of the key count. The root depends only on the current
+ * key-value set, not on the order of inserts and deletes.
+ *
+ * 哈希 / Hashes:
+ * - 叶子 / leaf = H(0x00 || H(key) || H(value))
+ * - 内部节点 / inner node = H(0x01 || left || right)
+ *
+ * 节点不可变并通过 `Arc` 共享，修改只复制从根到叶子的路径；`snapshot` 只复制根指针。
+ * Nodes are immutable and shared through `Arc`, so an update copies only the root-to-leaf path
+ * and `snapshot` copies just the root pointer.
+ */
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use std::sync::Arc;
+
+/// 叶子哈希的域分隔前缀 / Domain separation prefix of leaf hashes
+const LEAF_PREFIX: u8 = 0x00;
+/// 内部节点哈希的域分隔前缀 / Domain separation prefix of inner node hashes
+const NODE_PREFIX: u8 = 0x01;
+
+/// 树节点 / Tree node
+#[derive(Debug)]
+enum Node {
+    Empty,
+    Leaf {
+        key_hash: Vec<u8>,
+        value: Vec<u8>,
+        hash: Vec<u8>,
+    },
+    Internal {
+        left: Arc<Node>,
+        right: Arc<Node>,
+        hash: Vec<u8>,
+    },
+}
+
+/// 证明末端的叶子 / Leaf at the end of a proof path
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofLeaf {
+    /// 叶子键的哈希 / Hash of the leaf key
+    #[serde_as(as = "Hex")]
+    pub key_hash: Vec<u8>,
+    /// 叶子值的哈希 / Hash of the leaf value
+    #[serde_as(as = "Hex")]
+    pub value_hash: Vec<u8>,
+}
+
+/// 包含或不包含证明 / Inclusion or non-inclusion proof
+///
+/// 路径终止于空子树（`leaf` 为 `None`）或一个叶子。不包含证明中的叶子属于另一个与被查询键共享路径前缀的键。
+/// The path ends at an empty subtree (`leaf` is `None`) or at a leaf. In a non-inclusion proof
+/// that leaf belongs to another key sharing the queried key's path prefix.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SparseMerkleProof {
+    /// 路径末端的叶子 / Leaf at the end of the path
+    pub leaf: Option<ProofLeaf>,
+    /// 自顶向下的兄弟节点哈希 / Top-down sibling hashes
+    #[serde_as(as = "Vec<Hex>")]
+    pub siblings: Vec<Vec<u8>>,
+}
+
+impl SparseMerkleProof {
+    /// 验证键值对包含在给定根下 / Verify that a key-value pair is included under the root
+    pub fn verify_inclusion(
+        &self,
+        algorithm: HashAlgorithm,
+        root: &[u8],
+        key: &[u8],
+        value: &[u8],
+    ) -> bool {
+        let key_hash = BaseAlgorithm::hash(algorithm, key);
+        match &self.leaf {
+            Some(leaf)
+                if leaf.key_hash == key_hash
+                    && leaf.value_hash == BaseAlgorithm::hash(algorithm, value) =>
+            {
+                self.compute_root(algorithm, &key_hash) == root
+            }
+            _ => false,
+        }
+    }
+
+    /// 验证键不在给定根下 / Verify that a key is absent under the root
+    pub fn verify_non_inclusion(&self, algorithm: HashAlgorithm, root: &[u8], key: &[u8]) -> bool {
+        let key_hash = BaseAlgorithm::hash(algorithm, key);
+        if let Some(leaf) = &self.leaf {
+            // 另一个叶子必须位于被查询键的路径上 / The other leaf must sit on the queried key's path
+            if leaf.key_hash == key_hash
+                || leaf.key_hash.len() != key_hash.len()
+                || (0..self.siblings.len()).any(|i| bit(&leaf.key_hash, i) != bit(&key_hash, i))
+            {
+                return false;
+            }
+        }
+        self.compute_root(algorithm, &key_hash) == root
+    }
+
+    /// 沿键的路径自底向上计算根 / Compute the root bottom-up along the key's path
+    fn compute_root(&self, algorithm: HashAlgorithm, key_hash: &[u8]) -> Vec<u8> {
+        if self.siblings.len() > key_hash.len() * 8 {
+            return Vec::new();
+        }
+        let bottom = match &self.leaf {
+            Some(leaf) => leaf_hash(algorithm, &leaf.key_hash, &leaf.value_hash),
+            None => empty_hash(algorithm),
+        };
+        self.siblings
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(bottom, |current, (depth, sibling)| {
+                if bit(key_hash, depth) {
+                    node_hash(algorithm, sibling, &current)
+                } else {
+                    node_hash(algorithm, &current, sibling)
+                }
+            })
+    }
+}
+
+/// 稀疏默克尔树，克隆即为廉价快照 / Sparse Merkle tree; a clone is a cheap snapshot
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+    algorithm: HashAlgorithm,
+    root: Arc<Node>,
+    len: usize,
+}
+
+impl Default for SparseMerkleTree {
+    /// 使用 SHA-256 的空树 / Empty tree using SHA-256
+    fn default() -> Self {
+        Self::new(HashAlgorithm::Sha256)
+    }
+}
+
+impl SparseMerkleTree {
+    /// 创建空树 / Create an empty tree
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        SparseMerkleTree {
+            algorithm,
+            root: Arc::new(Node::Empty),
+            len: 0,
+        }
+    }
+
+    /// 状态根，空树为全零 / State root, all zeros for an empty tree
+    pub fn root_hash(&self) -> Vec<u8> {
+        self.node_hash(&self.root)
+    }
+
+    /// 哈希算法 / Hash algorithm
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// 键的数量 / Number of keys
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否为空 / Whether the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 读取键对应的值 / Get the value of a key
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        let key_hash = BaseAlgorithm::hash(self.algorithm, key);
+        let mut node = &self.root;
+        let mut depth = 0;
+        loop {
+            match node.as_ref() {
+                Node::Empty => return None,
+                Node::Leaf {
+                    key_hash: leaf_key,
+                    value,
+                    ..
+                } => return (*leaf_key == key_hash).then_some(value.as_slice()),
+                Node::Internal { left, right, .. } => {
+                    node = if bit(&key_hash, depth) { right } else { left };
+                    depth += 1;
+                }
+            }
+        }
+    }
+
+    /// 写入键值，返回旧值 / Put a key-value pair, returning the previous value
+    pub fn put(&mut self, key: &[u8], value: Vec<u8>) -> Option<Vec<u8>> {
+        let key_hash = BaseAlgorithm::hash(self.algorithm, key);
+        let previous = self.get(key).map(<[u8]>::to_vec);
+        let leaf = self.new_leaf(key_hash.clone(), value);
+        self.root = self.insert(&self.root, &key_hash, leaf, 0);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// 删除键，返回旧值 / Delete a key, returning the previous value
+    pub fn delete(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+        let key_hash = BaseAlgorithm::hash(self.algorithm, key);
+        let previous = self.get(key).map(<[u8]>::to_vec)?;
+        if let Some(root) = self.remove(&self.root, &key_hash, 0) {
+            self.root = root;
+            self.len -= 1;
+        }
+        Some(previous)
+    }
+
+    /// 当前状态的只读快照，与原树共享全部节点 / Snapshot of the current state sharing every node
+    pub fn snapshot(&self) -> Self {
+        self.clone()
+    }
+
+    /// 生成键的证明，键存在时为包含证明，否则为不包含证明
+    /// Generate a proof for a key: inclusion if present, non-inclusion otherwise
+    pub fn prove(&self, key: &[u8]) -> SparseMerkleProof {
+        let key_hash = BaseAlgorithm::hash(self.algorithm, key);
+        let mut siblings = Vec::new();
+        let mut node = &self.root;
+        loop {
+            match node.as_ref() {
+                Node::Empty => {
+                    return SparseMerkleProof {
+                        leaf: None,
+                        siblings,
+                    }
+                }
+                Node::Leaf {
+                    key_hash, value, ..
+                } => {
+                    let leaf = ProofLeaf {
+                        key_hash: key_hash.clone(),
+                        value_hash: BaseAlgorithm::hash(self.algorithm, value),
+                    };
+                    return SparseMerkleProof {
+                        leaf: Some(leaf),
+                        siblings,
+                    };
+                }
+                Node::Internal { left, right, .. } => {
+                    let (next, sibling) = if bit(&key_hash, siblings.len()) {
+                        (right, left)
+                    } else {
+                        (left, right)
+                    };
+                    siblings.push(self.node_hash(sibling));
+                    node = next;
+                }
+            }
+        }
+    }
+
+    /// 在子树中插入叶子，返回新子树 / Insert a leaf into a subtree, returning the new subtree
+    fn insert(
+        &self,
+        node: &Arc<Node>,
+        key_hash: &[u8],
+        leaf: Arc<Node>,
+        depth: usize,
+    ) -> Arc<Node> {
+        match node.as_ref() {
+            Node::Empty => leaf,
+            Node::Leaf {
+                key_hash: existing, ..
+            } if existing == key_hash => leaf,
+            Node::Leaf {
+                key_hash: existing, ..
+            } => self.split(node.clone(), existing, leaf, key_hash, depth),
+            Node::Internal { left, right, .. } => {
+                if bit(key_hash, depth) {
+                    self.new_internal(left.clone(), self.insert(right, key_hash, leaf, depth + 1))
+                } else {
+                    self.new_internal(self.insert(left, key_hash, leaf, depth + 1), right.clone())
+                }
+            }
+        }
+    }
+
+    /// 将两个叶子下推到路径第一次分叉处 / Push two leaves down to where their paths diverge
+    fn split(
+        &self,
+        existing: Arc<Node>,
+        existing_key: &[u8],
+        leaf: Arc<Node>,
+        key_hash: &[u8],
+        depth: usize,
+    ) -> Arc<Node> {
+        let empty = Arc::new(Node::Empty);
+        match (bit(existing_key, depth), bit(key_hash, depth)) {
+            (false, true) => self.new_internal(existing, leaf),
+            (true, false) => self.new_internal(leaf, existing),
+            (false, false) => self.new_internal(
+                self.split(existing, existing_key, leaf, key_hash, depth + 1),
+                empty,
+            ),
+            (true, true) => self.new_internal(
+                empty,
+                self.split(existing, existing_key, leaf, key_hash, depth + 1),
+            ),
+        }
+    }
+
+    /// 从子树删除键，键不存在时返回 `None` / Remove a key from a subtree, `None` if absent
+    fn remove(&self, node: &Arc<Node>, key_hash: &[u8], depth: usize) -> Option<Arc<Node>> {
+        match node.as_ref() {
+            Node::Empty => None,
+            Node::Leaf {
+                key_hash: existing, ..
+            } => (existing == key_hash).then(|| Arc::new(Node::Empty)),
+            Node::Internal { left, right, .. } => {
+                let (left, right) = if bit(key_hash, depth) {
+                    (left.clone(), self.remove(right, key_hash, depth + 1)?)
+                } else {
+                    (self.remove(left, key_hash, depth + 1)?, right.clone())
+                };
+                // 只剩一个叶子时上移，保持树的压缩形式 / Lift a lone leaf to keep the tree compact
+                Some(match (left.as_ref(), right.as_ref()) {
+                    (Node::Empty, Node::Empty) => left,
+                    (Node::Empty, Node::Leaf { .. }) => right,
+                    (Node::Leaf { .. }, Node::Empty) => left,
+                    _ => self.new_internal(left, right),
+                })
+            }
+        }
+    }
+
+    /// 创建叶子节点 / Create a leaf node
+    fn new_leaf(&self, key_hash: Vec<u8>, value: Vec<u8>) -> Arc<Node> {
+        let value_hash = BaseAlgorithm::hash(self.algorithm, &value);
+        let hash = leaf_hash(self.algorithm, &key_hash, &value_hash);
+        Arc::new(Node::Leaf {
+            key_hash,
+            value,
+            hash,
+        })
+    }
+
+    /// 创建内部节点 / Create an inner node
+    fn new_internal(&self, left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+        let hash = node_hash(
+            self.algorithm,
+            &self.node_hash(&left),
+            &self.node_hash(&right),
+        );
+        Arc::new(Node::Internal { left, right, hash })
+    }
+
+    /// 节点哈希 / Node hash
+    fn node_hash(&self, node: &Node) -> Vec<u8> {
+        match node {
+            Node::Empty => empty_hash(self.algorithm),
+            Node::Leaf { hash, .. } | Node::Internal { hash, .. } => hash.clone(),
+        }
+    }
+}
+
+/// 空子树哈希：全零 / Empty subtree hash: all zeros
+fn empty_hash(algorithm: HashAlgorithm) -> Vec<u8> {
+    vec![0u8; algorithm.output_len()]
+}
+
+/// H(0x00 || 键哈希 || 值哈希) / H(0x00 || key hash || value hash)
+fn leaf_hash(algorithm: HashAlgorithm, key_hash: &[u8], value_hash: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(1 + key_hash.len() + value_hash.len());
+    input.push(LEAF_PREFIX);
+    input.extend_from_slice(key_hash);
+    input.extend_from_slice(value_hash);
+    BaseAlgorithm::hash(algorithm, &input)
+}
+
+/// H(0x01 || 左 || 右) / H(0x01 || left || right)
+fn node_hash(algorithm: HashAlgorithm, left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(1 + left.len() + right.len());
+    input.push(NODE_PREFIX);
+    input.extend_from_slice(left);
+    input.extend_from_slice(right);
+    BaseAlgorithm::hash(algorithm, &input)
+}
+
+/// 第 `index` 位，最高位在前，1 表示向右 / Bit `index`, most significant first; 1 means right
+fn bit(key_hash: &[u8], index: usize) -> bool {
+    (key_hash[index / 8] >> (7 - index % 8)) & 1 == 1
+}
@@ -0,0 +1,246 @@
+/*
+ * BIP-39 助记词 / BIP-39 mnemonic code
+ *
+ * 将随机熵编码为便于手写备份的12到24个单词，并通过 PBKDF2-HMAC-SHA512 派生64字节（512位）种子。
+ * 种子可交给 `ECDSAAlgorithm::generate_private_key_from_seed` 或 BIP-32 主密钥生成私钥。
+ * Encodes random entropy as 12 to 24 words suitable for a handwritten backup, and derives a
+ * 64-byte (512-bit) seed with PBKDF2-HMAC-SHA512. The seed feeds
+ * `ECDSAAlgorithm::generate_private_key_from_seed` or a BIP-32 master key.
+ *
+ * 单词表来自 BIP-39 官方仓库 / Wordlists come from the official BIP-39 repository:
+ * - english.txt
+ * - chinese_simplified.txt
+ */
+use crate::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use openssl::hash::MessageDigest;
+use openssl::pkcs5::pbkdf2_hmac;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use std::fmt;
+use std::sync::OnceLock;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
+
+/// 支持的单词数量 / Supported word counts
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+/// 种子长度 / Seed length
+pub const SEED_LEN: usize = 64;
+/// 每个单词编码的位数 / Bits encoded by each word
+const BITS_PER_WORD: usize = 11;
+/// PBKDF2 迭代次数 / PBKDF2 iteration count
+const PBKDF2_ROUNDS: usize = 2048;
+/// PBKDF2 盐值前缀 / PBKDF2 salt prefix
+const SALT_PREFIX: &str = "mnemonic";
+
+static ENGLISH: OnceLock<Vec<&'static str>> = OnceLock::new();
+static CHINESE_SIMPLIFIED: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+/// 助记词错误类型 / Mnemonic error type
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MnemonicError {
+    #[error("单词数量必须为12、15、18、21或24 / Word count must be 12, 15, 18, 21 or 24, got {0}")]
+    InvalidWordCount(usize),
+
+    #[error(
+        "熵长度必须为16到32字节且为4的倍数 / Entropy must be 16 to 32 bytes in steps of 4, got {0}"
+    )]
+    InvalidEntropyLength(usize),
+
+    #[error("单词不在单词表中 / Unknown word: {0}")]
+    UnknownWord(String),
+
+    #[error("助记词校验和错误 / Invalid mnemonic checksum")]
+    InvalidChecksum,
+
+    #[error("种子派生失败 / Seed derivation failed: {0}")]
+    SeedDerivation(String),
+}
+
+/// 单词表语言 / Wordlist language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// 英文 / English
+    English,
+    /// 简体中文 / Simplified Chinese
+    ChineseSimplified,
+}
+
+impl Language {
+    /// 获取2048个单词的单词表 / Get the 2048-word list
+    pub fn word_list(self) -> &'static [&'static str] {
+        let (cell, source) = match self {
+            Language::English => (&ENGLISH, include_str!("wordlist/english.txt")),
+            Language::ChineseSimplified => (
+                &CHINESE_SIMPLIFIED,
+                include_str!("wordlist/chinese_simplified.txt"),
+            ),
+        };
+        cell.get_or_init(|| source.lines().collect())
+    }
+
+    /// 查找单词的索引 / Find the index of a word
+    pub fn find_word(self, word: &str) -> Option<u16> {
+        let words = self.word_list();
+        match self {
+            // 英文单词表按字母排序 / The English list is sorted
+            Language::English => words.binary_search(&word).ok(),
+            Language::ChineseSimplified => words.iter().position(|&w| w == word),
+        }
+        .map(|index| index as u16)
+    }
+}
+
+/// BIP-39 助记词 / BIP-39 mnemonic
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    language: Language,
+    entropy: Vec<u8>,
+}
+
+impl Mnemonic {
+    /// 生成指定单词数量的随机助记词 / Generate a random mnemonic with the given word count
+    ///
+    /// # 参数
+    /// * `word_count`: 单词数量，12、15、18、21或24
+    /// * `language`: 单词表语言
+    /// # Parameters
+    /// * `word_count`: Word count, 12, 15, 18, 21 or 24
+    /// * `language`: Wordlist language
+    pub fn generate(word_count: usize, language: Language) -> Result<Self, MnemonicError> {
+        if !WORD_COUNTS.contains(&word_count) {
+            return Err(MnemonicError::InvalidWordCount(word_count));
+        }
+        // 每3个单词对应32位熵 / Every 3 words carry 32 bits of entropy
+        let mut entropy = vec![0u8; word_count / 3 * 4];
+        OsRng.fill_bytes(&mut entropy);
+        Self::from_entropy(&entropy, language)
+    }
+
+    /// 由熵创建助记词 / Create a mnemonic from entropy
+    ///
+    /// # 参数
+    /// * `entropy`: 16、20、24、28或32字节的熵
+    /// * `language`: 单词表语言
+    /// # Parameters
+    /// * `entropy`: 16, 20, 24, 28 or 32 bytes of entropy
+    /// * `language`: Wordlist language
+    pub fn from_entropy(entropy: &[u8], language: Language) -> Result<Self, MnemonicError> {
+        if entropy.len() < 16 || entropy.len() > 32 || !entropy.len().is_multiple_of(4) {
+            return Err(MnemonicError::InvalidEntropyLength(entropy.len()));
+        }
+        Ok(Mnemonic {
+            language,
+            entropy: entropy.to_vec(),
+        })
+    }
+
+    /// 解析助记词并校验校验和 / Parse a mnemonic phrase and validate its checksum
+    ///
+    /// 单词之间可以使用任意空白字符分隔 / Words may be separated by any whitespace
+    ///
+    /// # 参数
+    /// * `phrase`: 助记词
+    /// * `language`: 单词表语言
+    /// # Parameters
+    /// * `phrase`: Mnemonic phrase
+    /// * `language`: Wordlist language
+    pub fn from_phrase(phrase: &str, language: Language) -> Result<Self, MnemonicError> {
+        let phrase: String = phrase.nfkd().collect();
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if !WORD_COUNTS.contains(&words.len()) {
+            return Err(MnemonicError::InvalidWordCount(words.len()));
+        }
+
+        let mut bits = Vec::with_capacity(words.len() * BITS_PER_WORD);
+        for word in &words {
+            let index = language
+                .find_word(word)
+                .ok_or_else(|| MnemonicError::UnknownWord(word.to_string()))?;
+            bits.extend((0..BITS_PER_WORD).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        // 总位数 = 熵位数 + 熵位数/32 / Total bits = entropy bits + entropy bits / 32
+        let entropy_bits = bits.len() * 32 / 33;
+        let entropy: Vec<u8> = bits[..entropy_bits]
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+            .collect();
+        if Self::checksum_bits(&entropy) != bits[entropy_bits..] {
+            return Err(MnemonicError::InvalidChecksum);
+        }
+        Ok(Mnemonic { language, entropy })
+    }
+
+    /// 获取助记词，单词之间以空格分隔 / Get the phrase with words separated by spaces
+    pub fn phrase(&self) -> String {
+        self.words().join(" ")
+    }
+
+    /// 获取单词列表 / Get the words
+    pub fn words(&self) -> Vec<&'static str> {
+        let word_list = self.language.word_list();
+        let mut bits: Vec<bool> = self
+            .entropy
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect();
+        bits.extend(Self::checksum_bits(&self.entropy));
+        bits.chunks(BITS_PER_WORD)
+            .map(|chunk| {
+                let index = chunk
+                    .iter()
+                    .fold(0usize, |acc, &bit| (acc << 1) | bit as usize);
+                word_list[index]
+            })
+            .collect()
+    }
+
+    /// 获取熵 / Get the entropy
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// 获取单词表语言 / Get the wordlist language
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// 使用口令派生64字节种子 / Derive the 64-byte seed with a passphrase
+    ///
+    /// 种子 = PBKDF2-HMAC-SHA512(NFKD(助记词), "mnemonic" + NFKD(口令), 2048)，口令可为空
+    /// seed = PBKDF2-HMAC-SHA512(NFKD(phrase), "mnemonic" + NFKD(passphrase), 2048); the
+    /// passphrase may be empty
+    pub fn to_seed(&self, passphrase: &str) -> Result<[u8; SEED_LEN], MnemonicError> {
+        let phrase: String = self.phrase().nfkd().collect();
+        let salt: String = format!("{}{}", SALT_PREFIX, passphrase).nfkd().collect();
+        let mut seed = [0u8; SEED_LEN];
+        pbkdf2_hmac(
+            phrase.as_bytes(),
+            salt.as_bytes(),
+            PBKDF2_ROUNDS,
+            MessageDigest::sha512(),
+            &mut seed,
+        )
+        .map_err(|e| MnemonicError::SeedDerivation(e.to_string()))?;
+        Ok(seed)
+    }
+
+    /// 计算校验和位：SHA-256(熵) 的前 熵位数/32 位
+    /// Compute the checksum bits: the first (entropy bits / 32) bits of SHA-256(entropy)
+    fn checksum_bits(entropy: &[u8]) -> Vec<bool> {
+        let hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, entropy);
+        (0..entropy.len() * 8 / 32)
+            .map(|i| (hash[i / 8] >> (7 - i % 8)) & 1 == 1)
+            .collect()
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    /// 不输出助记词和熵 / Never prints the phrase or entropy
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("language", &self.language)
+            .field("word_count", &(self.entropy.len() * 3 / 4))
+            .finish_non_exhaustive()
+    }
+}
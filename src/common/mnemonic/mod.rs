@@ -0,0 +1 @@
+pub mod mnemonic_code;
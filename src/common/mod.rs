@@ -1,3 +1,6 @@
 pub mod algorithm;
 pub mod exception;
+pub mod keystore;
+pub mod merkle;
+pub mod mnemonic;
 pub mod timer;
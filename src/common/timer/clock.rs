@@ -0,0 +1,243 @@
+/*
+ * 时钟抽象 / Clock abstraction
+ *
+ * TimerManager 通过 `Clock` 获取当前时间（单调时间与墙上时间）和等待，生产环境使用基于 tokio 的
+ * `SystemClock`，测试使用手动推进的 `MockClock`，使依赖定时器的逻辑（如共识超时）可以在毫秒内
+ * 确定性地测试。
+ * TimerManager reads the time (monotonic and wall-clock) and waits through `Clock`: production
+ * uses the tokio based `SystemClock`, tests use the manually advanced `MockClock`, so timer
+ * driven logic such as consensus timeouts can be tested deterministically in milliseconds.
+ */
+use chrono::{DateTime, Utc};
+use futures::channel::oneshot;
+use futures::future::{BoxFuture, FutureExt};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// `MockClock` 每次唤醒后让出执行权的次数，使被唤醒的任务有机会运行并登记下一次等待
+/// Number of times `MockClock` yields after a wake-up so woken tasks can run and register their
+/// next wait
+const SETTLE_YIELDS: usize = 16;
+
+/// 时钟 trait，提供当前时间和等待 / Clock trait providing the current time and waiting
+pub trait Clock: Send + Sync + 'static {
+    /// 当前时间 / Current time
+    fn now(&self) -> Instant;
+
+    /// 当前墙上时间 / Current wall-clock time
+    fn now_utc(&self) -> DateTime<Utc>;
+
+    /// 等待到指定时间，时间已过则立即完成 / Wait until a deadline, completing at once if it passed
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()>;
+
+    /// 等待指定时长 / Wait for a duration
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.sleep_until(self.now() + duration)
+    }
+
+    /// 等待到指定墙上时间，时间已过则立即完成
+    /// Wait until a wall-clock time, completing at once if it passed
+    fn sleep_until_utc(&self, at: DateTime<Utc>) -> BoxFuture<'static, ()> {
+        self.sleep((at - self.now_utc()).to_std().unwrap_or_default())
+    }
+}
+
+/// 系统时钟，基于 tokio 定时器 / System clock backed by tokio timers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        tokio::time::sleep_until(deadline.into()).boxed()
+    }
+}
+
+/// 固定速率的间隔定时器，语义与 `tokio::time::interval` 相同：第一次 `tick` 立即完成，
+/// 落后时连续触发以追上进度
+/// Fixed-rate interval with the semantics of `tokio::time::interval`: the first `tick`
+/// completes at once and missed ticks fire back to back to catch up
+pub struct Interval {
+    clock: Arc<dyn Clock>,
+    next: Instant,
+    period: Duration,
+}
+
+impl Interval {
+    /// 创建间隔定时器，`period` 为零时 panic
+    /// Create an interval; panics if `period` is zero
+    pub fn new(clock: Arc<dyn Clock>, period: Duration) -> Self {
+        assert!(!period.is_zero(), "`period` must be non-zero.");
+        let next = clock.now();
+        Interval {
+            clock,
+            next,
+            period,
+        }
+    }
+
+    /// 下一次触发的计划时间 / Scheduled time of the next tick
+    pub fn next_tick(&self) -> Instant {
+        self.next
+    }
+
+    /// 等待下一次触发，返回该次触发的计划时间
+    /// Wait for the next tick and return its scheduled time
+    pub async fn tick(&mut self) -> Instant {
+        let scheduled = self.next;
+        self.clock.sleep_until(scheduled).await;
+        self.next = scheduled + self.period;
+        scheduled
+    }
+}
+
+/// 手动推进的模拟时钟，时间只在调用 `advance` 或 `jump` 时前进
+/// Manually advanced mock clock; time only moves when `advance` or `jump` is called
+///
+/// 克隆共享同一时间线，测试可保留一份克隆，另一份交给 `TimerManager::with_clock`。
+/// Clones share one timeline, so a test can keep a clone and hand another to
+/// `TimerManager::with_clock`.
+#[derive(Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockState>>,
+}
+
+struct MockState {
+    origin: Instant,
+    origin_utc: DateTime<Utc>,
+    now: Instant,
+    sleepers: Vec<(Instant, oneshot::Sender<()>)>,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    /// 创建模拟时钟，起点为创建时的系统时间 / Create a mock clock starting at the current time
+    pub fn new() -> Self {
+        Self::starting_at(Utc::now())
+    }
+
+    /// 创建墙上时间从指定时刻开始的模拟时钟，用于测试日历调度
+    /// Create a mock clock whose wall-clock time starts at the given instant, for testing
+    /// calendar schedules
+    pub fn starting_at(start: DateTime<Utc>) -> Self {
+        let now = Instant::now();
+        MockClock {
+            state: Arc::new(Mutex::new(MockState {
+                origin: now,
+                origin_utc: start,
+                now,
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// 尚未到期的等待数量 / Number of waits that have not yet expired
+    pub fn pending_sleeps(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.sleepers.retain(|(_, sender)| !sender.is_canceled());
+        state.sleepers.len()
+    }
+
+    /// 将时间推进指定时长 / Advance the time by a duration
+    ///
+    /// 时间按到期顺序逐个跳到每个等待的截止时间并唤醒它，之后让出执行权，
+    /// 使被唤醒的任务在时间继续前进前运行；因此周期任务在推进期间的每个周期都会触发。
+    /// Time jumps from deadline to deadline in order, waking each wait and then yielding so the
+    /// woken tasks run before time moves on; a periodic task therefore fires for every period
+    /// covered by the advance.
+    pub async fn advance(&self, duration: Duration) {
+        let target = self.now() + duration;
+        settle().await;
+        loop {
+            let next = self
+                .state
+                .lock()
+                .unwrap()
+                .sleepers
+                .iter()
+                .map(|(deadline, _)| *deadline)
+                .filter(|deadline| *deadline <= target)
+                .min();
+            match next {
+                Some(deadline) => self.wake(deadline),
+                None => break,
+            }
+            settle().await;
+        }
+        self.wake(target);
+    }
+
+    /// 一次性跳过指定时长，模拟进程暂停或系统休眠
+    /// Skip a duration in one step, simulating a paused process or a suspended system
+    ///
+    /// 与 `advance` 不同，期间到期的等待在跳转后同时唤醒，任务只能在跳转之后观察到时间。
+    /// Unlike `advance`, the waits expiring in between are all woken after the jump, so tasks
+    /// only observe the time once it has passed.
+    pub async fn jump(&self, duration: Duration) {
+        settle().await;
+        self.wake(self.now() + duration);
+        settle().await;
+    }
+
+    /// 将时间设为 `now`（不回退）并唤醒所有到期的等待
+    /// Set the time to `now` (never backwards) and wake every expired wait
+    fn wake(&self, now: Instant) {
+        let due = {
+            let mut state = self.state.lock().unwrap();
+            state.now = state.now.max(now);
+            let now = state.now;
+            let (due, pending) = state
+                .sleepers
+                .drain(..)
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        for (_, sender) in due {
+            let _ = sender.send(());
+        }
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap().now
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        let state = self.state.lock().unwrap();
+        state.origin_utc + (state.now - state.origin)
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        let mut state = self.state.lock().unwrap();
+        if deadline <= state.now {
+            return async {}.boxed();
+        }
+        let (sender, receiver) = oneshot::channel();
+        state.sleepers.push((deadline, sender));
+        async move {
+            let _ = receiver.await;
+        }
+        .boxed()
+    }
+}
+
+/// 让出执行权若干次 / Yield a number of times
+async fn settle() {
+    for _ in 0..SETTLE_YIELDS {
+        tokio::task::yield_now().await;
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * Cron 表达式调度 / Cron expression schedules
+ *
+ * 表达式包含秒字段：`秒 分 时 日 月 周 [年]`，如 `0 0 2 * * *` 表示每天 02:00:00。
+ * 表达式在指定时区中求值，夏令时切换由 `chrono-tz` 处理，计算结果统一转换为 UTC。
+ * Expressions include a seconds field: `sec min hour day-of-month month day-of-week [year]`,
+ * e.g. `0 0 2 * * *` is every day at 02:00:00. They are evaluated in the given time zone with
+ * daylight saving transitions handled by `chrono-tz`, and results are converted to UTC.
+ */
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Cron 调度错误类型 / Cron schedule error type
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CronError {
+    #[error("无效的cron表达式 / Invalid cron expression `{expression}`: {reason}")]
+    InvalidExpression { expression: String, reason: String },
+}
+
+/// 某个时区中的 cron 调度 / Cron schedule in a time zone
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    schedule: cron::Schedule,
+    timezone: Tz,
+}
+
+impl CronSchedule {
+    /// 解析 cron 表达式 / Parse a cron expression
+    ///
+    /// # 参数
+    /// * `expression`: 含秒字段的表达式，如 `0 0 * * * *`（每小时整点）
+    /// * `timezone`: 求值时区，如 `chrono_tz::Asia::Shanghai`
+    /// # Parameters
+    /// * `expression`: Expression with a seconds field, e.g. `0 0 * * * *` (top of every hour)
+    /// * `timezone`: Time zone to evaluate in, e.g. `chrono_tz::Asia::Shanghai`
+    pub fn new(expression: &str, timezone: Tz) -> Result<Self, CronError> {
+        let schedule =
+            cron::Schedule::from_str(expression).map_err(|e| CronError::InvalidExpression {
+                expression: expression.to_string(),
+                reason: e.to_string(),
+            })?;
+        Ok(CronSchedule { schedule, timezone })
+    }
+
+    /// 以 UTC 求值的 cron 调度 / Cron schedule evaluated in UTC
+    pub fn utc(expression: &str) -> Result<Self, CronError> {
+        Self::new(expression, Tz::UTC)
+    }
+
+    /// 原始表达式 / Source expression
+    pub fn expression(&self) -> &str {
+        self.schedule.source()
+    }
+
+    /// 求值时区 / Evaluation time zone
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// 严格晚于 `after` 的下一次触发时间，没有后续触发时返回 `None`
+    /// Next fire time strictly after `after`, or `None` if the schedule has no more runs
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.schedule
+            .after(&after.with_timezone(&self.timezone))
+            .next()
+            .map(|next| next.with_timezone(&Utc))
+    }
+
+    /// 区间 (`after`, `until`] 内的触发时间，按时间顺序
+    /// Fire times in (`after`, `until`], in chronological order
+    pub fn fire_times_between(
+        &self,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        self.schedule
+            .after(&after.with_timezone(&self.timezone))
+            .map(|next| next.with_timezone(&Utc))
+            .take_while(move |next| *next <= until)
+    }
+}
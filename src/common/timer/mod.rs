@@ -1 +1,3 @@
-pub mod timer_manager;
\ No newline at end of file
+pub mod clock;
+pub mod cron_schedule;
+pub mod timer_manager;
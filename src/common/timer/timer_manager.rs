@@ -1,11 +1,296 @@
-use actix::prelude::{Actor, Context, Handler, Message};
-use tokio::time::{sleep, Duration, interval};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use crate::common::timer::clock::{Clock, Interval, SystemClock};
+use crate::common::timer::cron_schedule::CronSchedule;
+use actix::fut::wrap_future;
+use actix::prelude::{
+    Actor, ActorFutureExt, AsyncContext, Context, Handler, Message, MessageResponse,
+};
+use chrono::{DateTime, Utc};
+use futures::future::{BoxFuture, FutureExt};
+use serde::Serialize;
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 
-/// 定义 TimerManager 结构体，它是一个 Actor
-/// The TimerManager struct, which is an Actor
-pub struct TimerManager;
+/// 定时任务句柄，包含任务ID和取消令牌
+/// Handle of a scheduled task holding its ID and cancellation token
+///
+/// 句柄可以克隆；调用 `cancel` 与向 `TimerManager` 发送 `Cancel` 效果相同。
+/// The handle can be cloned; calling `cancel` has the same effect as sending `Cancel` to the
+/// `TimerManager`.
+#[derive(Debug, Clone, MessageResponse)]
+pub struct TimerHandle {
+    id: u64,
+    token: CancellationToken,
+}
+
+impl TimerHandle {
+    /// 获取任务ID
+    /// Get the task ID
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 取消任务，正在执行的动作会运行完毕，之后不再触发
+    /// Cancel the task; a running action finishes but the task never fires again
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// 任务是否已被取消
+    /// Whether the task has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+/// 周期任务的动作发生 panic 后的重启策略
+/// Restart policy applied after the action of a periodic task panics
+///
+/// panic 会被捕获并通过 `log::error!` 记录，不会影响其他任务或 TimerManager 本身。
+/// 一次性任务（`Schedule`、`ScheduleAt`）的动作只能执行一次，不适用重启策略。
+/// Panics are caught and logged with `log::error!` and never affect other tasks or the
+/// TimerManager itself. One-shot tasks (`Schedule`, `ScheduleAt`) run their action only once, so
+/// the policy does not apply to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RestartPolicy {
+    /// 第一次 panic 后停止任务
+    /// Stop the task after the first panic
+    #[default]
+    Never,
+    /// 总是在下一个周期继续执行
+    /// Always carry on with the next period
+    Always,
+    /// 最多重启指定次数，之后停止任务
+    /// Restart at most this many times, then stop the task
+    Limited(u32),
+}
+
+impl RestartPolicy {
+    /// 已重启 `restarts` 次后是否允许再次重启
+    /// Whether another restart is allowed after `restarts` restarts
+    fn allows(self, restarts: u32) -> bool {
+        match self {
+            RestartPolicy::Never => false,
+            RestartPolicy::Always => true,
+            RestartPolicy::Limited(max) => restarts < max,
+        }
+    }
+}
+
+/// 日历任务错过触发时间（如进程暂停或系统休眠）后的处理策略
+/// Policy for calendar tasks whose fire times were missed, e.g. after a paused process or a
+/// suspended system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedRunPolicy {
+    /// 只执行一次，丢弃其余错过的触发
+    /// Run once and drop the other missed fire times
+    #[default]
+    Skip,
+    /// 为每个错过的触发时间依次执行一次，最多 `MAX_CATCH_UP_RUNS` 次，其余丢弃
+    /// Run once for every missed fire time, one after another, at most `MAX_CATCH_UP_RUNS`
+    /// times; the rest are dropped
+    CatchUp,
+}
+
+/// `MissedRunPolicy::CatchUp` 一次补执行的最大次数，避免长时间暂停后在 Actor 上连续执行
+/// Maximum runs of one `MissedRunPolicy::CatchUp` round, so a long pause does not make the
+/// action run back to back on the actor indefinitely
+pub const MAX_CATCH_UP_RUNS: usize = 100;
+
+/// 任务类型，周期以毫秒为单位 / Kind of task, periods in milliseconds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerKind {
+    /// `Schedule`
+    Once,
+    /// `ScheduleAt`
+    At,
+    /// `ScheduleAtFixedRate`
+    FixedRate { period: u64 },
+    /// `ScheduleWithFixedDelay`
+    FixedDelay { period: u64 },
+    /// `ScheduleCron`
+    Cron { expression: String },
+}
+
+/// `ListTimers` 返回的任务信息 / Task information returned by `ListTimers`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimerInfo {
+    pub id: u64,
+    pub name: String,
+    pub kind: TimerKind,
+    /// 下一次计划执行的墙上时间，动作执行期间为 `None`
+    /// Wall-clock time of the next planned run, `None` while the action is running
+    pub next_fire: Option<DateTime<Utc>>,
+    /// 动作已执行的次数（含 panic 的执行） / Number of action runs, including panicked ones
+    pub run_count: u64,
+    /// 最近一次执行的耗时 / Duration of the latest run
+    pub last_duration: Option<Duration>,
+    /// 最近一次 panic 的信息，之后的成功执行不会清除
+    /// Message of the latest panic; later successful runs do not clear it
+    pub last_error: Option<String>,
+    /// 固定速率任务中动作耗时超过周期的次数，其他任务恒为 0
+    /// Number of runs of a fixed-rate task that took longer than `period`; always 0 for other
+    /// kinds
+    pub overruns: u64,
+}
+
+/// 任务运行统计，由任务更新、`ListTimers` 读取
+/// Runtime statistics of a task, updated by the task and read by `ListTimers`
+#[derive(Default)]
+struct TimerStats {
+    next_fire: Option<DateTime<Utc>>,
+    run_count: u64,
+    last_duration: Option<Duration>,
+    last_error: Option<String>,
+    overruns: u64,
+}
+
+/// TimerManager 登记的任务 / Task registered in the TimerManager
+struct TimerEntry {
+    handle: TimerHandle,
+    name: String,
+    kind: TimerKind,
+    stats: Arc<Mutex<TimerStats>>,
+}
+
+/// 任务侧的统计记录器 / Task-side recorder of the statistics
+struct Tracker {
+    id: u64,
+    clock: Arc<dyn Clock>,
+    stats: Arc<Mutex<TimerStats>>,
+    /// 固定速率任务的周期，执行耗时超过它即计为超时
+    /// Period of a fixed-rate task; a run taking longer counts as an overrun
+    overrun_after: Option<Duration>,
+}
+
+impl Tracker {
+    /// 记录下一次执行的墙上时间 / Record the wall-clock time of the next run
+    fn set_next_fire(&self, at: DateTime<Utc>) {
+        self.stats.lock().unwrap().next_fire = Some(at);
+    }
+
+    /// 记录下一次执行的单调时间 / Record the monotonic time of the next run
+    fn set_next_fire_instant(&self, at: Instant) {
+        let delay = at.saturating_duration_since(self.clock.now());
+        self.set_next_fire(self.clock.now_utc() + delay);
+    }
+
+    /// 执行一次动作并记录次数、耗时、panic 信息和超时，返回动作是否正常完成
+    /// Run the action once, recording the count, duration, panic message and overrun; returns
+    /// whether the action completed normally
+    async fn run<F>(&self, action: F) -> bool
+    where
+        F: FnOnce() -> BoxFuture<'static, ()>,
+    {
+        self.stats.lock().unwrap().next_fire = None;
+        let start = self.clock.now();
+        let result = run_guarded(self.id, action).await;
+        let elapsed = self.clock.now().saturating_duration_since(start);
+
+        let mut stats = self.stats.lock().unwrap();
+        stats.run_count += 1;
+        stats.last_duration = Some(elapsed);
+        if let Some(period) = self.overrun_after.filter(|period| elapsed > *period) {
+            stats.overruns += 1;
+            let id = self.id;
+            log::warn!("定时任务 {id} 执行超过周期 / Timer {id} overran: {elapsed:?} > {period:?}");
+        }
+        match result {
+            Ok(()) => true,
+            Err(message) => {
+                stats.last_error = Some(message);
+                false
+            }
+        }
+    }
+}
+
+/// 定义 TimerManager 结构体，它是一个 Actor，记录尚未结束的任务及其运行统计
+/// The TimerManager struct, an Actor that tracks the tasks that have not finished and their
+/// runtime statistics
+pub struct TimerManager {
+    next_id: u64,
+    timers: HashMap<u64, TimerEntry>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for TimerManager {
+    fn default() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+}
+
+impl TimerManager {
+    /// 创建使用系统时钟、没有任务的 TimerManager
+    /// Create a TimerManager with no tasks using the system clock
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建使用指定时钟的 TimerManager，测试中可传入 `MockClock`
+    /// Create a TimerManager using the given clock, such as a `MockClock` in tests
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        TimerManager {
+            next_id: 0,
+            timers: HashMap::new(),
+            clock,
+        }
+    }
+
+    /// 登记新任务并在 Actor 上下文中运行，任务结束或被取消后自动移除
+    /// Register a new task and run it on the actor context; it is removed once it finishes or
+    /// is cancelled
+    fn spawn<F, Fut>(
+        &mut self,
+        ctx: &mut Context<Self>,
+        name: String,
+        kind: TimerKind,
+        task: F,
+    ) -> TimerHandle
+    where
+        F: FnOnce(Tracker, CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.next_id += 1;
+        let handle = TimerHandle {
+            id: self.next_id,
+            token: CancellationToken::new(),
+        };
+        let id = handle.id;
+        let stats = Arc::new(Mutex::new(TimerStats::default()));
+        let tracker = Tracker {
+            id,
+            clock: self.clock.clone(),
+            stats: stats.clone(),
+            overrun_after: match kind {
+                TimerKind::FixedRate { period } => Some(Duration::from_millis(period)),
+                _ => None,
+            },
+        };
+        let token = handle.token.clone();
+        self.timers.insert(
+            id,
+            TimerEntry {
+                handle: handle.clone(),
+                name,
+                kind,
+                stats,
+            },
+        );
+
+        ctx.spawn(
+            wrap_future::<_, Self>(task(tracker, token)).map(move |_, act, _| {
+                act.timers.remove(&id);
+            }),
+        );
+        handle
+    }
+}
 
 /// 为 TimerManager 实现 Actor trait
 /// Implement the Actor trait for TimerManager
@@ -16,82 +301,387 @@ impl Actor for TimerManager {
 /// 定义 Schedule 消息，用于在指定延迟后执行任务
 /// Define the Schedule message for executing a task after a specified delay
 #[derive(Message)]
-#[rtype(result = "()")]
+#[rtype(result = "TimerHandle")]
 pub struct Schedule {
-    pub action: Box<dyn FnOnce() + Send + 'static>,
+    pub name: String,
+    pub action: Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send + 'static>,
     pub delay: u64,
 }
 
-/// 定义 ScheduleAtFixedRate 消息，用于以固定速率执行任务
-/// Define the ScheduleAtFixedRate message for executing a task at a fixed rate
+/// 定义 ScheduleAtFixedRate 消息，用于以固定速率执行任务，`period` 为零时按1毫秒处理
+/// Define the ScheduleAtFixedRate message for executing a task at a fixed rate; a zero `period`
+/// is treated as 1 millisecond
 #[derive(Message)]
-#[rtype(result = "()")]
+#[rtype(result = "TimerHandle")]
 pub struct ScheduleAtFixedRate {
-    pub action: Box<dyn Fn() + Send + 'static>,
+    pub name: String,
+    pub action: Box<dyn Fn() -> BoxFuture<'static, ()> + Send + 'static>,
     pub initial_delay: u64,
     pub period: u64,
+    pub restart: RestartPolicy,
 }
 
 /// 定义 ScheduleWithFixedDelay 消息，用于以固定延迟执行任务
 /// Define the ScheduleWithFixedDelay message for executing a task with a fixed delay
 #[derive(Message)]
-#[rtype(result = "()")]
+#[rtype(result = "TimerHandle")]
 pub struct ScheduleWithFixedDelay {
-    pub action: Box<dyn Fn() + Send + 'static>,
+    pub name: String,
+    pub action: Box<dyn Fn() -> BoxFuture<'static, ()> + Send + 'static>,
     pub initial_delay: u64,
     pub period: u64,
+    pub restart: RestartPolicy,
+}
+
+/// 定义 ScheduleAt 消息，用于在指定的墙上时间执行一次任务，时间已过则立即执行
+/// Define the ScheduleAt message for executing a task once at a wall-clock time, immediately if
+/// the time has passed
+#[derive(Message)]
+#[rtype(result = "TimerHandle")]
+pub struct ScheduleAt {
+    pub name: String,
+    pub action: Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send + 'static>,
+    pub at: DateTime<Utc>,
 }
 
+/// 定义 ScheduleCron 消息，用于按 cron 表达式在固定的墙上时间执行任务
+/// Define the ScheduleCron message for executing a task at wall-clock times given by a cron
+/// expression
+#[derive(Message)]
+#[rtype(result = "TimerHandle")]
+pub struct ScheduleCron {
+    pub name: String,
+    pub action: Box<dyn Fn() -> BoxFuture<'static, ()> + Send + 'static>,
+    pub schedule: CronSchedule,
+    pub missed: MissedRunPolicy,
+    pub restart: RestartPolicy,
+}
+
+/// 定义 Cancel 消息，用于取消指定ID的任务，任务存在时返回 `true`
+/// Define the Cancel message for cancelling the task with the given ID; returns `true` if it
+/// existed
+#[derive(Message)]
+#[rtype(result = "bool")]
+pub struct Cancel {
+    pub id: u64,
+}
+
+/// 定义 CancelAll 消息，用于取消所有任务，返回被取消的任务数量
+/// Define the CancelAll message for cancelling every task; returns the number cancelled
+#[derive(Message)]
+#[rtype(result = "usize")]
+pub struct CancelAll;
+
+/// 定义 ListTimers 消息，返回所有未结束任务的信息，按ID排序
+/// Define the ListTimers message returning information on every unfinished task, ordered by ID
+#[derive(Message)]
+#[rtype(result = "Vec<TimerInfo>")]
+pub struct ListTimers;
+
 /// 为 TimerManager 实现处理 Schedule 消息的功能
 /// Implement the handler for the Schedule message in TimerManager
 impl Handler<Schedule> for TimerManager {
-    type Result = ();
+    type Result = TimerHandle;
 
-    fn handle(&mut self, msg: Schedule, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: Schedule, ctx: &mut Self::Context) -> TimerHandle {
         let action = msg.action;
-        let delay = msg.delay;
-        actix::spawn(async move {
-            sleep(Duration::from_millis(delay)).await;
-            action();
-        });
+        let clock = self.clock.clone();
+        let deadline = clock.now() + Duration::from_millis(msg.delay);
+        self.spawn(ctx, msg.name, TimerKind::Once, move |tracker, token| {
+            tracker.set_next_fire_instant(deadline);
+            async move {
+                tokio::select! {
+                    _ = token.cancelled() => {}
+                    _ = clock.sleep_until(deadline) => {
+                        tracker.run(action).await;
+                    }
+                }
+            }
+        })
     }
 }
 
 /// 为 TimerManager 实现处理 ScheduleAtFixedRate 消息的功能
 /// Implement the handler for the ScheduleAtFixedRate message in TimerManager
 impl Handler<ScheduleAtFixedRate> for TimerManager {
-    type Result = ();
+    type Result = TimerHandle;
 
-    fn handle(&mut self, msg: ScheduleAtFixedRate, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: ScheduleAtFixedRate, ctx: &mut Self::Context) -> TimerHandle {
         let action = msg.action;
-        let initial_delay = msg.initial_delay;
-        let period = msg.period;
-        actix::spawn(async move {
-            sleep(Duration::from_millis(initial_delay)).await;
-            let mut interval = interval(Duration::from_millis(period));
-            loop {
-                interval.tick().await;
-                action();
+        // 零周期会使 Interval::new 在任务外 panic 并终止整个 TimerManager，因此在这里修正
+        // A zero period would make Interval::new panic outside the task guard and stop the
+        // whole TimerManager, so it is corrected here
+        if msg.period == 0 {
+            log::warn!(
+                "定时任务 {} 的周期为0，按1毫秒处理 / Zero period treated as 1ms",
+                msg.name
+            );
+        }
+        let period = msg.period.max(1);
+        let restart = msg.restart;
+        let clock = self.clock.clone();
+        let start = clock.now() + Duration::from_millis(msg.initial_delay);
+        let kind = TimerKind::FixedRate { period };
+        self.spawn(ctx, msg.name, kind, move |tracker, token| {
+            tracker.set_next_fire_instant(start);
+            async move {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = clock.sleep_until(start) => {}
+                }
+                let mut interval = Interval::new(clock, Duration::from_millis(period));
+                let mut restarts = 0;
+                loop {
+                    tracker.set_next_fire_instant(interval.next_tick());
+                    tokio::select! {
+                        _ = token.cancelled() => break,
+                        _ = interval.tick() => {}
+                    }
+                    if !run_periodic(&tracker, &action, restart, &mut restarts).await {
+                        break;
+                    }
+                }
             }
-        });
+        })
     }
 }
 
 /// 为 TimerManager 实现处理 ScheduleWithFixedDelay 消息的功能
 /// Implement the handler for the ScheduleWithFixedDelay message in TimerManager
 impl Handler<ScheduleWithFixedDelay> for TimerManager {
-    type Result = ();
+    type Result = TimerHandle;
 
-    fn handle(&mut self, msg: ScheduleWithFixedDelay, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: ScheduleWithFixedDelay, ctx: &mut Self::Context) -> TimerHandle {
         let action = msg.action;
-        let initial_delay = msg.initial_delay;
         let period = msg.period;
-        actix::spawn(async move {
-            sleep(Duration::from_millis(initial_delay)).await;
-            loop {
-                action();
-                sleep(Duration::from_millis(period)).await;
+        let restart = msg.restart;
+        let clock = self.clock.clone();
+        let start = clock.now() + Duration::from_millis(msg.initial_delay);
+        let kind = TimerKind::FixedDelay { period };
+        self.spawn(ctx, msg.name, kind, move |tracker, token| {
+            tracker.set_next_fire_instant(start);
+            async move {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = clock.sleep_until(start) => {}
+                }
+                let mut restarts = 0;
+                loop {
+                    if !run_periodic(&tracker, &action, restart, &mut restarts).await {
+                        break;
+                    }
+                    let next = clock.now() + Duration::from_millis(period);
+                    tracker.set_next_fire_instant(next);
+                    tokio::select! {
+                        _ = token.cancelled() => break,
+                        _ = clock.sleep_until(next) => {}
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// 为 TimerManager 实现处理 ScheduleAt 消息的功能
+/// Implement the handler for the ScheduleAt message in TimerManager
+impl Handler<ScheduleAt> for TimerManager {
+    type Result = TimerHandle;
+
+    fn handle(&mut self, msg: ScheduleAt, ctx: &mut Self::Context) -> TimerHandle {
+        let action = msg.action;
+        let at = msg.at;
+        let clock = self.clock.clone();
+        self.spawn(ctx, msg.name, TimerKind::At, move |tracker, token| {
+            tracker.set_next_fire(at);
+            async move {
+                tokio::select! {
+                    _ = token.cancelled() => {}
+                    _ = clock.sleep_until_utc(at) => {
+                        tracker.run(action).await;
+                    }
+                }
             }
-        });
+        })
     }
-}
\ No newline at end of file
+}
+
+/// 为 TimerManager 实现处理 ScheduleCron 消息的功能
+/// Implement the handler for the ScheduleCron message in TimerManager
+impl Handler<ScheduleCron> for TimerManager {
+    type Result = TimerHandle;
+
+    fn handle(&mut self, msg: ScheduleCron, ctx: &mut Self::Context) -> TimerHandle {
+        let action = msg.action;
+        let schedule = msg.schedule;
+        let missed = msg.missed;
+        let restart = msg.restart;
+        let clock = self.clock.clone();
+        let kind = TimerKind::Cron {
+            expression: schedule.expression().to_string(),
+        };
+        self.spawn(ctx, msg.name, kind, move |tracker, token| async move {
+            let mut restarts = 0;
+            let mut next = schedule.next_after(clock.now_utc());
+            while let Some(fire_at) = next {
+                tracker.set_next_fire(fire_at);
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = clock.sleep_until_utc(fire_at) => {}
+                }
+                // 暂停期间可能错过了多次触发；不早于 fire_at 计算下一次，避免重复执行
+                // Several fire times may have passed during a pause; the next one is computed
+                // from no earlier than fire_at so a run is never repeated
+                let now = clock.now_utc().max(fire_at);
+                let runs = match missed {
+                    MissedRunPolicy::Skip => 1,
+                    MissedRunPolicy::CatchUp => {
+                        let mut fire_times = schedule.fire_times_between(fire_at, now);
+                        let runs = 1 + fire_times.by_ref().take(MAX_CATCH_UP_RUNS - 1).count();
+                        if fire_times.next().is_some() {
+                            log::warn!(
+                                "定时任务 {} 错过的触发超过{MAX_CATCH_UP_RUNS}次，其余被丢弃 / \
+                                 Timer {} missed more than {MAX_CATCH_UP_RUNS} runs, dropping the rest",
+                                tracker.id,
+                                tracker.id
+                            );
+                        }
+                        runs
+                    }
+                };
+                if runs > 1 {
+                    log::warn!(
+                        "定时任务 {} 补执行 {runs} 次 / Timer {} catching up {runs} missed runs",
+                        tracker.id,
+                        tracker.id
+                    );
+                }
+                for _ in 0..runs {
+                    if token.is_cancelled()
+                        || !run_periodic(&tracker, &action, restart, &mut restarts).await
+                    {
+                        return;
+                    }
+                }
+                // 跳过策略同样丢弃动作执行期间经过的触发时间
+                // The skip policy also drops fire times that passed while the action ran
+                next = match missed {
+                    MissedRunPolicy::Skip => schedule.next_after(clock.now_utc().max(now)),
+                    MissedRunPolicy::CatchUp => schedule.next_after(now),
+                };
+            }
+        })
+    }
+}
+
+/// 为 TimerManager 实现处理 Cancel 消息的功能
+/// Implement the handler for the Cancel message in TimerManager
+impl Handler<Cancel> for TimerManager {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Cancel, _ctx: &mut Self::Context) -> bool {
+        match self.timers.remove(&msg.id) {
+            Some(entry) => {
+                entry.handle.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// 为 TimerManager 实现处理 CancelAll 消息的功能
+/// Implement the handler for the CancelAll message in TimerManager
+impl Handler<CancelAll> for TimerManager {
+    type Result = usize;
+
+    fn handle(&mut self, _msg: CancelAll, _ctx: &mut Self::Context) -> usize {
+        let count = self.timers.len();
+        for (_, entry) in self.timers.drain() {
+            entry.handle.cancel();
+        }
+        count
+    }
+}
+
+/// 为 TimerManager 实现处理 ListTimers 消息的功能
+/// Implement the handler for the ListTimers message in TimerManager
+impl Handler<ListTimers> for TimerManager {
+    type Result = Vec<TimerInfo>;
+
+    fn handle(&mut self, _msg: ListTimers, _ctx: &mut Self::Context) -> Vec<TimerInfo> {
+        let mut timers: Vec<TimerInfo> = self
+            .timers
+            .iter()
+            .map(|(&id, entry)| {
+                let stats = entry.stats.lock().unwrap();
+                TimerInfo {
+                    id,
+                    name: entry.name.clone(),
+                    kind: entry.kind.clone(),
+                    next_fire: stats.next_fire,
+                    run_count: stats.run_count,
+                    last_duration: stats.last_duration,
+                    last_error: stats.last_error.clone(),
+                    overruns: stats.overruns,
+                }
+            })
+            .collect();
+        timers.sort_by_key(|timer| timer.id);
+        timers
+    }
+}
+
+/// 执行一次动作并捕获 panic，panic 时记录错误并返回其信息
+/// Run an action once, catching panics; on a panic the error is logged and its message returned
+///
+/// 动作在 `catch_unwind` 内部调用，因此创建 future 时的 panic 也会被捕获。
+/// The action is invoked inside `catch_unwind`, so a panic while creating the future is caught
+/// as well.
+async fn run_guarded<F>(id: u64, action: F) -> Result<(), String>
+where
+    F: FnOnce() -> BoxFuture<'static, ()>,
+{
+    AssertUnwindSafe(async move { action().await })
+        .catch_unwind()
+        .await
+        .map_err(|payload| {
+            let message = panic_message(payload.as_ref());
+            log::error!("定时任务 {id} 执行时发生 panic / Timer {id} action panicked: {message}");
+            message
+        })
+}
+
+/// 执行一次周期任务的动作并应用重启策略，返回任务是否应继续
+/// Run the action of a periodic task once and apply the restart policy; returns whether the
+/// task should carry on
+async fn run_periodic<F>(
+    tracker: &Tracker,
+    action: &F,
+    restart: RestartPolicy,
+    restarts: &mut u32,
+) -> bool
+where
+    F: Fn() -> BoxFuture<'static, ()> + ?Sized,
+{
+    if tracker.run(action).await {
+        return true;
+    }
+    if !restart.allows(*restarts) {
+        let id = tracker.id;
+        log::error!("定时任务 {id} 已停止 / Timer {id} stopped after a panic");
+        return false;
+    }
+    *restarts += 1;
+    true
+}
+
+/// 提取 panic 信息 / Extract the panic message
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
@@ -1,6 +1,7 @@
-use blockchain_rs::common::algorithm::aes_algorithm::AESAlgorithm;
+use blockchain_rs::common::algorithm::aes_algorithm::{AESAlgorithm, AesCiphertext, AesMode};
 
 #[test]
+#[allow(deprecated)]
 fn test_aes_ecb() {
     let key = b"0123456789abcdef"; // 16字节密钥
     let data = b"Hello, AES ECB!";
@@ -16,13 +17,74 @@ fn test_aes_ecb() {
     println!("解密字节: {:?}", decrypted);
     println!();
     assert_eq!(decrypted, data);
+
+    // 与显式选择 ECB 模式的结果相同
+    let envelope = AESAlgorithm::encrypt(AesMode::Ecb, key, data, None).unwrap();
+    assert_eq!(envelope.ciphertext, encrypted);
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_invalid_key_length() {
     let invalid_key = b"short_key";
     let data = b"test";
 
     assert!(AESAlgorithm::encode(invalid_key, data).is_err());
     assert!(AESAlgorithm::decode(invalid_key, data).is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_aes_gcm_round_trip() {
+    let key = b"0123456789abcdef0123456789abcdef"; // 32字节密钥
+    let data = b"Hello, AES GCM!";
+    let aad = b"header";
+
+    let envelope = AESAlgorithm::encrypt(AesMode::Gcm, key, data, Some(aad)).unwrap();
+    assert_eq!(envelope.mode, AesMode::Gcm);
+    assert_eq!(envelope.iv.len(), 12);
+    assert_eq!(envelope.tag.len(), 16);
+
+    let decrypted = AESAlgorithm::decrypt(key, &envelope, Some(aad)).unwrap();
+    assert_eq!(decrypted, data);
+
+    // 关联数据不一致或密文被篡改时必须校验失败
+    assert!(AESAlgorithm::decrypt(key, &envelope, Some(b"other")).is_err());
+    let mut tampered = envelope.clone();
+    tampered.ciphertext[0] ^= 0x01;
+    assert!(AESAlgorithm::decrypt(key, &tampered, Some(aad)).is_err());
+}
+
+#[test]
+fn test_aes_cbc_ctr_with_iv() {
+    let key = b"0123456789abcdef";
+    let iv = [7u8; 16];
+    let data = b"Hello, AES CBC and CTR!";
+
+    let cbc = AESAlgorithm::encrypt_with_iv(AesMode::Cbc, key, &iv, data, None).unwrap();
+    assert_eq!(cbc.ciphertext.len() % 16, 0);
+    assert_eq!(AESAlgorithm::decrypt(key, &cbc, None).unwrap(), data);
+
+    let ctr = AESAlgorithm::encrypt_with_iv(AesMode::Ctr, key, &iv, data, None).unwrap();
+    assert_eq!(ctr.ciphertext.len(), data.len());
+    assert_eq!(AESAlgorithm::decrypt(key, &ctr, None).unwrap(), data);
+
+    // IV长度错误、非GCM模式传入关联数据均应报错
+    assert!(AESAlgorithm::encrypt_with_iv(AesMode::Cbc, key, &iv[..8], data, None).is_err());
+    assert!(AESAlgorithm::encrypt_with_iv(AesMode::Ctr, key, &iv, data, Some(b"aad")).is_err());
+}
+
+#[test]
+fn test_aes_envelope_serialization() {
+    let key = b"0123456789abcdef0123456789abcdef";
+    let data = b"keystore payload";
+    let envelope = AESAlgorithm::encrypt(AesMode::Gcm, key, data, None).unwrap();
+
+    let bytes = envelope.to_bytes();
+    assert_eq!(AesCiphertext::from_bytes(&bytes).unwrap(), envelope);
+    assert!(AesCiphertext::from_bytes(&bytes[..5]).is_err());
+
+    let json = serde_json::to_string(&envelope).unwrap();
+    assert!(json.contains("\"mode\":\"GCM\""));
+    let parsed: AesCiphertext = serde_json::from_str(&json).unwrap();
+    assert_eq!(AESAlgorithm::decrypt(key, &parsed, None).unwrap(), data);
+}
@@ -1,4 +1,4 @@
-use blockchain_rs::common::algorithm::base_58_algorithm::Base58Algorithm;
+use blockchain_rs::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Error, Base58Version};
 #[test]
 fn test_encode() {
     let input = b"hello";
@@ -21,3 +21,38 @@ fn test_decode_to_big_integer() {
     let encoded =  Base58Algorithm::encode(input);
     let _big_integer = Base58Algorithm::decode_to_big_integer(&encoded).unwrap();
 }
+
+#[test]
+fn test_encode_check() {
+    // 全零 HASH160 的主网地址
+    let encoded = Base58Algorithm::encode_check(0x00, &[0u8; 20]);
+    assert_eq!(encoded, "1111111111111111111114oLvT2");
+
+    let (version, payload) = Base58Algorithm::decode_check(&encoded).unwrap();
+    assert_eq!(version, 0x00);
+    assert_eq!(payload, vec![0u8; 20]);
+}
+
+#[test]
+fn test_validate_address() {
+    let address = "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs";
+    let hash = Base58Algorithm::validate_address(address, Base58Version::Mainnet).unwrap();
+    assert_eq!(hash.len(), 20);
+
+    // 版本字节不符
+    assert_eq!(
+        Base58Algorithm::validate_address(address, Base58Version::Testnet),
+        Err(Base58Error::VersionMismatch { expected: 0x6f, found: 0x00 })
+    );
+
+    // 输错一个字符时校验和不匹配
+    let mistyped = "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt";
+    assert!(matches!(
+        Base58Algorithm::validate_address(mistyped, Base58Version::Mainnet),
+        Err(Base58Error::ChecksumMismatch { .. })
+    ));
+
+    // 非法字符与长度不足
+    assert_eq!(Base58Algorithm::decode_check("0OIl"), Err(Base58Error::IllegalCharacter));
+    assert_eq!(Base58Algorithm::decode_check("1111"), Err(Base58Error::TooShort));
+}
@@ -1,13 +1,22 @@
-use blockchain_rs::common::algorithm::base_algorithm::BaseAlgorithm;
+use blockchain_rs::common::algorithm::base_algorithm::{
+    BaseAlgorithm, HashAlgorithm, HashError, Hasher,
+};
+
+/// 将字节数组转换为十六进制字符串
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
 
 #[test]
 fn test_encode() {
     // 待编码的数据
     let data = b"test data";
     // 调用 encode 函数进行编码
-    let result = BaseAlgorithm::encode("SHA-256", Some(data));
+    let result = BaseAlgorithm::encode("SHA-256", Some(data)).unwrap();
     // 确保结果不为 None
     assert!(result.is_some());
+    // 输入为 None 时返回 None
+    assert_eq!(BaseAlgorithm::encode("SHA-256", None).unwrap(), None);
 }
 
 #[test]
@@ -15,7 +24,58 @@ fn test_encode_twice() {
     // 待编码的数据
     let data = b"test data";
     // 调用 encode_twice 函数进行两次编码
-    let result = BaseAlgorithm::encode_twice("SHA-256", Some(data));
+    let result = BaseAlgorithm::encode_twice("SHA-256", Some(data)).unwrap();
     // 确保结果不为 None
     assert!(result.is_some());
-}
\ No newline at end of file
+    assert_eq!(
+        result.unwrap(),
+        BaseAlgorithm::hash(HashAlgorithm::Sha256, &BaseAlgorithm::hash(HashAlgorithm::Sha256, data))
+    );
+}
+
+#[test]
+fn test_unsupported_algorithm() {
+    // 不支持的算法返回错误而不是 panic
+    let result = BaseAlgorithm::encode("MD5", Some(b"test data"));
+    assert_eq!(result, Err(HashError::UnsupportedAlgorithm("MD5".to_string())));
+    assert!(BaseAlgorithm::encode_twice("MD5", None).is_err());
+}
+
+#[test]
+fn test_hash_vectors() {
+    // 各算法对 "abc" 的标准测试向量
+    let vectors = [
+        (HashAlgorithm::Sha256, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        (HashAlgorithm::Sha512, "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"),
+        (HashAlgorithm::Sha3_256, "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+        (HashAlgorithm::Keccak256, "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        (HashAlgorithm::Ripemd160, "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+        (HashAlgorithm::Blake2b, "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+        (HashAlgorithm::Blake3, "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"),
+        (HashAlgorithm::Sm3, "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0"),
+    ];
+    for (algorithm, expected) in vectors {
+        let digest = BaseAlgorithm::hash(algorithm, b"abc");
+        assert_eq!(digest.len(), algorithm.output_len());
+        assert_eq!(to_hex(&digest), expected, "{}", algorithm);
+        // 名称可以解析回同一个算法
+        assert_eq!(algorithm.name().parse::<HashAlgorithm>().unwrap(), algorithm);
+    }
+}
+
+#[test]
+fn test_incremental_hasher() {
+    let data = vec![0x5au8; 10_000];
+    for algorithm in HashAlgorithm::ALL {
+        let mut hasher = Hasher::new(algorithm);
+        for chunk in data.chunks(333) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), BaseAlgorithm::hash(algorithm, &data));
+    }
+
+    // 通过 io::Write 从流中读取
+    let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+    std::io::copy(&mut &data[..], &mut hasher).unwrap();
+    assert_eq!(hasher.finalize(), BaseAlgorithm::hash(HashAlgorithm::Sha256, &data));
+}
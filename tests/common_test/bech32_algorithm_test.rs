@@ -0,0 +1,86 @@
+use blockchain_rs::common::algorithm::bech32_algorithm::{Bech32Algorithm, Bech32Error, Bech32Variant};
+use super::hex_bytes;
+
+#[test]
+fn test_encode_decode() {
+    // BIP-173 / BIP-350 有效字符串
+    for (input, variant) in [
+        ("A12UEL5L", Bech32Variant::Bech32),
+        ("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", Bech32Variant::Bech32),
+        ("A1LQFN3A", Bech32Variant::Bech32m),
+        ("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", Bech32Variant::Bech32m),
+    ] {
+        let (hrp, data, found) = Bech32Algorithm::decode(input).unwrap();
+        assert_eq!(found, variant);
+        let encoded = Bech32Algorithm::encode(&hrp, &data, variant).unwrap();
+        assert_eq!(encoded, input.to_ascii_lowercase());
+    }
+}
+
+#[test]
+fn test_invalid_strings() {
+    assert_eq!(Bech32Algorithm::decode("A12uEL5L"), Err(Bech32Error::MixedCase));
+    assert_eq!(Bech32Algorithm::decode("pzry9x0s0muk"), Err(Bech32Error::MissingSeparator));
+    assert_eq!(Bech32Algorithm::decode("1pzry9x0s0muk"), Err(Bech32Error::InvalidHrp));
+    assert_eq!(
+        Bech32Algorithm::decode("x1b4n0q5v"),
+        Err(Bech32Error::InvalidCharacter { position: 2, character: 'b' })
+    );
+    // 非 ASCII 字符单独报错，位置为字符下标
+    assert_eq!(
+        Bech32Algorithm::decode("a1é2uel5l"),
+        Err(Bech32Error::NonAsciiCharacter { position: 2, character: 'é' })
+    );
+    assert_eq!(
+        Bech32Algorithm::decode("aé1b2uel5l"),
+        Err(Bech32Error::NonAsciiCharacter { position: 1, character: 'é' })
+    );
+}
+
+#[test]
+fn test_error_location() {
+    // 将最后一个字符 4 误写为 5
+    let mistyped = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5";
+    assert_eq!(
+        Bech32Algorithm::decode(mistyped),
+        Err(Bech32Error::InvalidChecksum { error_positions: vec![41] })
+    );
+
+    // 修改中间的一个字符
+    let mistyped = "bc1qw508d6qejxtdg4y5r3zarvery0c5xw7kv8f3t4";
+    match Bech32Algorithm::decode(mistyped) {
+        Err(Bech32Error::InvalidChecksum { error_positions }) => assert!(error_positions.contains(&26)),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_segwit_address() {
+    let program = hex_bytes("751e76e8199196d454941c45d1b3a323f1433bd6");
+    let address = Bech32Algorithm::encode_segwit_address("bc", 0, &program).unwrap();
+    assert_eq!(address, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+    assert_eq!(
+        Bech32Algorithm::decode_segwit_address("bc", "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap(),
+        (0, program)
+    );
+
+    // 见证版本 1 (Taproot) 使用 Bech32m
+    let taproot = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+    let (version, program) = Bech32Algorithm::decode_segwit_address("bc", taproot).unwrap();
+    assert_eq!(version, 1);
+    assert_eq!(program, hex_bytes("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"));
+    assert_eq!(Bech32Algorithm::encode_segwit_address("bc", 1, &program).unwrap(), taproot);
+
+    // 版本 1 使用 Bech32 校验和、人类可读部分不符均无效
+    let mut data = vec![1];
+    data.extend(Bech32Algorithm::convert_bits(&program, 8, 5, true).unwrap());
+    let wrong_variant = Bech32Algorithm::encode("bc", &data, Bech32Variant::Bech32).unwrap();
+    assert_eq!(
+        Bech32Algorithm::decode_segwit_address("bc", &wrong_variant),
+        Err(Bech32Error::InvalidVariant)
+    );
+    assert!(matches!(
+        Bech32Algorithm::decode_segwit_address("tb", taproot),
+        Err(Bech32Error::HrpMismatch { .. })
+    ));
+}
@@ -0,0 +1,131 @@
+use blockchain_rs::common::algorithm::bip32_algorithm::{
+    Bip32Error, Bip32Network, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey,
+    HARDENED_OFFSET,
+};
+use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use super::hex_bytes;
+
+#[test]
+fn test_bip32_vector_1() {
+    // BIP-32 测试向量 1
+    let master = ExtendedPrivateKey::from_seed(
+        &hex_bytes("000102030405060708090a0b0c0d0e0f"),
+        Bip32Network::Mainnet,
+    )
+    .unwrap();
+    let vectors = [
+        (
+            "m",
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+        ),
+        (
+            "m/0'",
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+        ),
+        (
+            "m/0'/1",
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+            "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+        ),
+        (
+            "m/0'/1/2'",
+            "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+            "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+        ),
+        (
+            "m/0'/1/2'/2",
+            "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+            "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+        ),
+        (
+            "m/0'/1/2'/2/1000000000",
+            "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+            "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+        ),
+    ];
+    for (path, xpub, xprv) in vectors {
+        let path: DerivationPath = path.parse().unwrap();
+        let key = master.derive_path(&path).unwrap();
+        assert_eq!(key.to_string(), xprv, "{}", path);
+        assert_eq!(key.extended_public_key().to_string(), xpub, "{}", path);
+
+        // 序列化可往返
+        assert_eq!(xprv.parse::<ExtendedPrivateKey>().unwrap(), key);
+        assert_eq!(
+            xpub.parse::<ExtendedPublicKey>().unwrap(),
+            key.extended_public_key()
+        );
+    }
+}
+
+#[test]
+fn test_public_derivation() {
+    let master = ExtendedPrivateKey::from_seed(&[7u8; 32], Bip32Network::Mainnet).unwrap();
+    let account = master.derive_path(&"m/44'/0'/0'".parse().unwrap()).unwrap();
+    let xpub = account.extended_public_key();
+
+    // 只用 xpub 派生收款地址，与私钥派生结果一致
+    for index in 0..5 {
+        let path = DerivationPath::new(vec![0, index]);
+        let from_private = account.derive_path(&path).unwrap();
+        let from_public = xpub.derive_path(&path).unwrap();
+        assert_eq!(from_private.extended_public_key(), from_public);
+        assert_eq!(from_private.public_key(), from_public.public_key());
+
+        // 派生出的私钥可直接签名
+        let signature = ECDSAAlgorithm::sign(&from_private.private_key(), b"payment").unwrap();
+        assert!(ECDSAAlgorithm::verify(&from_public.public_key(), b"payment", &signature).unwrap());
+        assert!(from_public.address().unwrap().starts_with('1'));
+    }
+
+    assert!(matches!(
+        xpub.derive_child(HARDENED_OFFSET),
+        Err(Bip32Error::HardenedFromPublic)
+    ));
+}
+
+#[test]
+fn test_derivation_path() {
+    let path: DerivationPath = "m/44'/0'/0'/0/5".parse().unwrap();
+    assert_eq!(
+        path.indices(),
+        &[44 + HARDENED_OFFSET, HARDENED_OFFSET, HARDENED_OFFSET, 0, 5]
+    );
+    assert_eq!(path.to_string(), "m/44'/0'/0'/0/5");
+    assert_eq!(
+        "m/44h/0H".parse::<DerivationPath>().unwrap(),
+        DerivationPath::new(vec![44 + HARDENED_OFFSET, HARDENED_OFFSET])
+    );
+    assert_eq!(
+        "m".parse::<DerivationPath>().unwrap().indices(),
+        &[] as &[u32]
+    );
+
+    for invalid in ["", "44'/0'", "m/", "m/-1", "m/2147483648", "m/1''", "m/x"] {
+        assert!(invalid.parse::<DerivationPath>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_invalid_extended_keys() {
+    assert!(matches!(
+        ExtendedPrivateKey::from_seed(&[0u8; 8], Bip32Network::Mainnet),
+        Err(Bip32Error::InvalidSeedLength(8))
+    ));
+
+    let master = ExtendedPrivateKey::from_seed(&[1u8; 16], Bip32Network::Testnet).unwrap();
+    let tprv = master.to_string();
+    let tpub = master.extended_public_key().to_string();
+    assert!(tprv.starts_with("tprv"));
+    assert!(tpub.starts_with("tpub"));
+    // 公私钥类型不匹配
+    assert!(tprv.parse::<ExtendedPublicKey>().is_err());
+    assert!(tpub.parse::<ExtendedPrivateKey>().is_err());
+
+    // 校验和错误
+    let mut corrupted = tpub.clone();
+    corrupted.replace_range(10..11, if &tpub[10..11] == "a" { "b" } else { "a" });
+    assert!(corrupted.parse::<ExtendedPublicKey>().is_err());
+}
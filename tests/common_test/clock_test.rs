@@ -0,0 +1,90 @@
+use blockchain_rs::common::timer::clock::{Clock, Interval, MockClock};
+use chrono::{TimeZone, Utc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 测试模拟时钟只在 advance 时前进，等待在截止时间到达时完成。
+/// Test that the mock clock only moves on advance and waits complete at their deadline.
+#[tokio::test]
+async fn test_mock_clock_sleep() {
+    let clock = MockClock::new();
+    let start = clock.now();
+    let done = Arc::new(AtomicBool::new(false));
+
+    let sleep = clock.sleep(Duration::from_millis(100));
+    let done_clone = done.clone();
+    tokio::spawn(async move {
+        sleep.await;
+        done_clone.store(true, Ordering::SeqCst);
+    });
+
+    clock.advance(Duration::from_millis(99)).await;
+    assert!(!done.load(Ordering::SeqCst));
+    assert_eq!(clock.pending_sleeps(), 1);
+
+    clock.advance(Duration::from_millis(1)).await;
+    assert!(done.load(Ordering::SeqCst));
+    assert_eq!(clock.pending_sleeps(), 0);
+    assert_eq!(clock.now() - start, Duration::from_millis(100));
+
+    // 已过去的截止时间立即完成 / A past deadline completes at once
+    clock.sleep_until(start).await;
+}
+
+/// 测试间隔定时器按周期触发，并在推进跨越多个周期时逐个触发。
+/// Test that the interval ticks every period, once per period when an advance spans several.
+#[tokio::test]
+async fn test_mock_clock_interval() {
+    let clock = MockClock::new();
+    let start = clock.now();
+    let ticks = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mut interval = Interval::new(Arc::new(clock.clone()), Duration::from_millis(10));
+    let ticks_clone = ticks.clone();
+    tokio::spawn(async move {
+        loop {
+            let scheduled = interval.tick().await;
+            ticks_clone.lock().unwrap().push(scheduled - start);
+        }
+    });
+
+    clock.advance(Duration::from_millis(35)).await;
+    let expected: Vec<_> = [0, 10, 20, 30]
+        .into_iter()
+        .map(Duration::from_millis)
+        .collect();
+    assert_eq!(*ticks.lock().unwrap(), expected);
+}
+
+/// 测试零周期的间隔定时器会 panic。
+/// Test that an interval with a zero period panics.
+#[test]
+#[should_panic(expected = "`period` must be non-zero.")]
+fn test_interval_zero_period() {
+    Interval::new(Arc::new(MockClock::new()), Duration::ZERO);
+}
+
+/// 测试墙上时间随模拟时间前进，jump 一次唤醒期间到期的所有等待。
+/// Test that wall-clock time follows mock time and that jump wakes every expired wait at once.
+#[tokio::test]
+async fn test_mock_clock_wall_time_and_jump() {
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = MockClock::starting_at(start);
+    let woken_at = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    for minutes in [1, 2] {
+        let sleep = clock.sleep_until_utc(start + chrono::Duration::minutes(minutes));
+        let woken_at = woken_at.clone();
+        let observer = clock.clone();
+        tokio::spawn(async move {
+            sleep.await;
+            woken_at.lock().unwrap().push(observer.now_utc());
+        });
+    }
+
+    clock.jump(Duration::from_secs(600)).await;
+    let expected = start + chrono::Duration::minutes(10);
+    assert_eq!(clock.now_utc(), expected);
+    assert_eq!(*woken_at.lock().unwrap(), vec![expected, expected]);
+}
@@ -0,0 +1,52 @@
+use blockchain_rs::common::timer::cron_schedule::{CronError, CronSchedule};
+use chrono::{TimeZone, Utc};
+use chrono_tz::Asia::Shanghai;
+
+/// 测试 UTC 中的 cron 调度，包括秒字段和区间内的触发时间。
+/// Test a cron schedule in UTC, including the seconds field and fire times in a range.
+#[test]
+fn test_cron_schedule_utc() {
+    let schedule = CronSchedule::utc("30 0 * * * *").unwrap();
+    assert_eq!(schedule.expression(), "30 0 * * * *");
+
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 30).unwrap();
+    // 严格晚于起点 / Strictly after the start
+    assert_eq!(
+        schedule.next_after(start),
+        Some(Utc.with_ymd_and_hms(2025, 1, 1, 11, 0, 30).unwrap())
+    );
+
+    let until = Utc.with_ymd_and_hms(2025, 1, 1, 14, 0, 30).unwrap();
+    let fire_times: Vec<_> = schedule.fire_times_between(start, until).collect();
+    assert_eq!(fire_times.len(), 4);
+    assert_eq!(fire_times.last(), Some(&until));
+}
+
+/// 测试时区：上海时间每天 02:00 对应 UTC 前一天 18:00。
+/// Test time zones: 02:00 every day in Shanghai is 18:00 UTC on the previous day.
+#[test]
+fn test_cron_schedule_timezone() {
+    let schedule = CronSchedule::new("0 0 2 * * *", Shanghai).unwrap();
+    assert_eq!(schedule.timezone(), Shanghai);
+
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        schedule.next_after(start),
+        Some(Utc.with_ymd_and_hms(2025, 6, 1, 18, 0, 0).unwrap())
+    );
+}
+
+/// 测试无效表达式和没有后续触发的表达式。
+/// Test invalid expressions and expressions with no further runs.
+#[test]
+fn test_cron_schedule_invalid() {
+    let result = CronSchedule::utc("0 0 25 * * *");
+    assert!(matches!(
+        result,
+        Err(CronError::InvalidExpression { ref expression, .. }) if expression == "0 0 25 * * *"
+    ));
+    assert!(CronSchedule::utc("not a cron").is_err());
+
+    let past = CronSchedule::utc("0 0 0 1 1 * 2020").unwrap();
+    assert_eq!(past.next_after(Utc::now()), None);
+}
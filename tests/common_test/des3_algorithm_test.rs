@@ -0,0 +1,67 @@
+use blockchain_rs::common::algorithm::des3_algorithm::DES3Algorithm;
+use blockchain_rs::common::exception::error_enum::ErrorNum;
+
+#[test]
+fn test_des3_ecb() {
+    let key = b"0123456789abcdefghijklmn"; // 24字节三密钥
+    let data = b"Hello, 3DES ECB!";
+
+    let encrypted = DES3Algorithm::encode(key, data).unwrap();
+    assert_ne!(encrypted, data);
+    assert_eq!(encrypted.len() % 8, 0);
+
+    let decrypted = DES3Algorithm::decode(key, &encrypted).unwrap();
+    assert_eq!(decrypted, data);
+}
+
+#[test]
+fn test_des3_known_vector() {
+    // 三个子密钥相同时 3DES 等价于单 DES：K=133457799BBCDFF1, P=0123456789ABCDEF, C=85E813540F0AB405
+    let des_key = [0x13, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let key = [des_key, des_key, des_key].concat();
+    let data = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+
+    let encrypted = DES3Algorithm::encode(&key, &data).unwrap();
+    assert_eq!(
+        encrypted[..8],
+        [0x85, 0xE8, 0x13, 0x54, 0x0F, 0x0A, 0xB4, 0x05]
+    );
+}
+
+#[test]
+fn test_des3_two_key_cbc() {
+    let two_key = b"0123456789abcdef"; // 16字节双密钥
+    let three_key = b"0123456789abcdef01234567"; // 等价的 K1K2K1
+    let iv = b"12345678";
+    let data = b"Hello, 3DES CBC with two keys!";
+
+    let encrypted = DES3Algorithm::encode_cbc(two_key, iv, data).unwrap();
+    assert_eq!(
+        encrypted,
+        DES3Algorithm::encode_cbc(three_key, iv, data).unwrap()
+    );
+    assert_ne!(encrypted, DES3Algorithm::encode(two_key, data).unwrap());
+
+    let decrypted = DES3Algorithm::decode_cbc(two_key, iv, &encrypted).unwrap();
+    assert_eq!(decrypted, data);
+}
+
+#[test]
+fn test_des3_errors() {
+    let data = b"test";
+    let err = DES3Algorithm::encode(b"short_key", data).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ErrorNum>(),
+        Some(&ErrorNum::Des3EncryptError)
+    );
+
+    let err = DES3Algorithm::encode_cbc(b"0123456789abcdef", b"1234", data).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ErrorNum>(),
+        Some(&ErrorNum::Des3EncryptError)
+    );
+
+    // 密文长度不是分组长度的整数倍
+    let err = DES3Algorithm::decode(b"0123456789abcdef", b"12345").unwrap_err();
+    assert_eq!(err.to_string(), "[002] DES3加解密错误");
+}
@@ -1,4 +1,11 @@
-use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use base64::{engine::general_purpose, Engine as _};
+use blockchain_rs::common::algorithm::base_58_algorithm::Base58Version;
+use blockchain_rs::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use blockchain_rs::common::algorithm::ecdsa_algorithm::{
+    AddressKind, ECDSAAlgorithm, EcdsaError, WifNetwork,
+};
+use super::hex_bytes;
+
 #[test]
 fn test_key_generation() {
     let priv_key = ECDSAAlgorithm::generate_private_key();
@@ -29,10 +36,27 @@ fn test_address_generation() {
     let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key,true).unwrap();
     let address = ECDSAAlgorithm::get_address(&pub_key).unwrap();
 
-    // 暂时注释掉长度断言，先调试
-    // assert_eq!(address.len(), 34);
     println!("Generated address: {}", address);
-    // assert!(address.starts_with("H"));
+    assert!(address.len() == 33 || address.len() == 34);
+    assert!(address.starts_with('1'));
+    assert!(ECDSAAlgorithm::validate_address(&address, Base58Version::Mainnet).is_ok());
+
+    let testnet = ECDSAAlgorithm::get_address_with_version(&pub_key, Base58Version::Testnet).unwrap();
+    assert!(testnet.starts_with('m') || testnet.starts_with('n'));
+    assert!(ECDSAAlgorithm::validate_address(&testnet, Base58Version::Mainnet).is_err());
+}
+
+#[test]
+fn test_known_address() {
+    // 比特币 wiki 中的公钥与地址示例
+    let pub_bytes = [
+        0x02, 0x50, 0x86, 0x3a, 0xd6, 0x4a, 0x87, 0xae, 0x8a, 0x2f, 0xe8, 0x3c, 0x1a, 0xf1, 0xa8,
+        0x40, 0x3c, 0xb5, 0x3f, 0x53, 0xe4, 0x86, 0xd8, 0x51, 0x1d, 0xad, 0x8a, 0x04, 0x88, 0x7e,
+        0x5b, 0x23, 0x52,
+    ];
+    let pub_key = general_purpose::STANDARD.encode(pub_bytes);
+    let address = ECDSAAlgorithm::get_address(&pub_key).unwrap();
+    assert_eq!(address, "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs");
 }
 
 #[test]
@@ -47,3 +71,274 @@ fn test_invalid_signature() {
 
     assert!(!verification);
 }
+
+#[test]
+fn test_segwit_address() {
+    // 生成元 G 的压缩公钥对应的 P2WPKH 地址（BIP-173 示例）
+    let mut pub_bytes = vec![0x02];
+    pub_bytes.extend_from_slice(&[
+        0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b,
+        0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8,
+        0x17, 0x98,
+    ]);
+    let pub_key = general_purpose::STANDARD.encode(&pub_bytes);
+    let address = ECDSAAlgorithm::get_segwit_address(&pub_key, "bc").unwrap();
+    assert_eq!(address, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+
+    // 非压缩公钥不能生成 P2WPKH 地址
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let long_pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, false).unwrap();
+    assert!(ECDSAAlgorithm::get_segwit_address(&long_pub_key, "bc").is_err());
+}
+
+#[test]
+fn test_parse_address() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+
+    let legacy = ECDSAAlgorithm::get_address(&pub_key).unwrap();
+    let segwit = ECDSAAlgorithm::get_segwit_address(&pub_key, "tb").unwrap();
+    let legacy_hash = match ECDSAAlgorithm::parse_address(&legacy).unwrap() {
+        AddressKind::Base58 { version, hash } => {
+            assert_eq!(version, Base58Version::Mainnet);
+            hash
+        }
+        other => panic!("unexpected address kind: {:?}", other),
+    };
+    match ECDSAAlgorithm::parse_address(&segwit).unwrap() {
+        AddressKind::SegWit { hrp, witness_version, program } => {
+            assert_eq!(hrp, "tb");
+            assert_eq!(witness_version, 0);
+            assert_eq!(program, legacy_hash);
+        }
+        other => panic!("unexpected address kind: {:?}", other),
+    }
+
+    assert!(ECDSAAlgorithm::parse_address("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").is_err());
+    assert!(ECDSAAlgorithm::parse_address("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt").is_err());
+}
+
+#[test]
+fn test_recoverable_signature() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+    let message_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, b"transfer 10 coins");
+
+    let signature = ECDSAAlgorithm::sign_recoverable(&priv_key, &message_hash).unwrap();
+    let sig_bytes = general_purpose::STANDARD.decode(&signature).unwrap();
+    assert_eq!(sig_bytes.len(), 65);
+    assert!(sig_bytes[64] <= 1);
+
+    let recovered = ECDSAAlgorithm::recover_public_key(&message_hash, &signature, true).unwrap();
+    assert_eq!(recovered, pub_key);
+    assert_eq!(
+        ECDSAAlgorithm::recover_address(&message_hash, &signature).unwrap(),
+        ECDSAAlgorithm::get_address(&pub_key).unwrap()
+    );
+
+    // 以太坊风格的 v = 27/28
+    let mut eth_bytes = sig_bytes.clone();
+    eth_bytes[64] += 27;
+    let eth_signature = general_purpose::STANDARD.encode(&eth_bytes);
+    assert_eq!(
+        ECDSAAlgorithm::recover_public_key(&message_hash, &eth_signature, true).unwrap(),
+        pub_key
+    );
+
+    // 消息不同则恢复出其他公钥
+    let other_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, b"transfer 99 coins");
+    if let Ok(other) = ECDSAAlgorithm::recover_public_key(&other_hash, &signature, true) {
+        assert_ne!(other, pub_key);
+    }
+}
+
+#[test]
+fn test_recoverable_signature_errors() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    assert!(matches!(
+        ECDSAAlgorithm::sign_recoverable(&priv_key, b"not a hash"),
+        Err(EcdsaError::InvalidHashLength(10))
+    ));
+
+    let message_hash = [7u8; 32];
+    let short = general_purpose::STANDARD.encode([0u8; 64]);
+    assert!(matches!(
+        ECDSAAlgorithm::recover_public_key(&message_hash, &short, true),
+        Err(EcdsaError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn test_rfc6979_vectors() {
+    // 私钥为 1 时的 RFC 6979 secp256k1 黄金向量（消息先做 SHA-256），签名为 r || s
+    let priv_key = general_purpose::STANDARD.encode(hex_bytes(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ));
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+    for (message, expected) in [
+        (
+            "Satoshi Nakamoto",
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d82442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+        ),
+        (
+            "All those moments will be lost in time, like tears in rain. Time to die...",
+            "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+        ),
+    ] {
+        let message_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, message.as_bytes());
+        let signature = ECDSAAlgorithm::sign_prehash(&priv_key, &message_hash).unwrap();
+        let der = general_purpose::STANDARD.decode(&signature).unwrap();
+        let parsed = k256::ecdsa::Signature::from_der(&der).unwrap();
+        assert_eq!(parsed.as_ref(), hex_bytes(expected).as_slice(), "{}", message);
+
+        // 多次签名结果一致，且与对原始数据签名相同
+        assert_eq!(ECDSAAlgorithm::sign_prehash(&priv_key, &message_hash).unwrap(), signature);
+        assert_eq!(ECDSAAlgorithm::sign(&priv_key, message.as_bytes()).unwrap(), signature);
+        assert!(ECDSAAlgorithm::verify_prehash(&pub_key, &message_hash, &signature).unwrap());
+        assert!(ECDSAAlgorithm::verify(&pub_key, message.as_bytes(), &signature).unwrap());
+    }
+}
+
+#[test]
+fn test_prehash_signature() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+    // 交易ID为双重 SHA-256
+    let txid = BaseAlgorithm::hash_twice(HashAlgorithm::Sha256, b"raw transaction");
+
+    let signature = ECDSAAlgorithm::sign_prehash(&priv_key, &txid).unwrap();
+    assert!(ECDSAAlgorithm::verify_prehash(&pub_key, &txid, &signature).unwrap());
+    assert!(!ECDSAAlgorithm::verify_prehash(&pub_key, &[0u8; 32], &signature).unwrap());
+    assert!(matches!(
+        ECDSAAlgorithm::sign_prehash(&priv_key, &txid[..31]),
+        Err(EcdsaError::InvalidHashLength(31))
+    ));
+    assert!(matches!(
+        ECDSAAlgorithm::verify_prehash(&pub_key, b"short", &signature),
+        Err(EcdsaError::InvalidHashLength(5))
+    ));
+}
+
+#[test]
+fn test_low_s_and_strict_verify() {
+    let data = b"test message";
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+    let signature = ECDSAAlgorithm::sign(&priv_key, data).unwrap();
+    let der = general_purpose::STANDARD.decode(&signature).unwrap();
+    let parsed = k256::ecdsa::Signature::from_der(&der).unwrap();
+    // 签名总是低S值
+    assert!(parsed.normalize_s().is_none());
+    assert!(ECDSAAlgorithm::verify_strict(&pub_key, data, &signature).unwrap());
+
+    // 将 s 替换为 n - s 得到高S值签名：宽松模式验证失败，严格模式返回错误
+    let high_s = k256::ecdsa::Signature::from_scalars(parsed.r().to_bytes(), (-*parsed.s()).to_bytes()).unwrap();
+    let high_s = general_purpose::STANDARD.encode(high_s.to_der().as_bytes());
+    assert!(!ECDSAAlgorithm::verify(&pub_key, data, &high_s).unwrap());
+    let hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, data);
+    assert!(!ECDSAAlgorithm::verify_prehash(&pub_key, &hash, &high_s).unwrap());
+    assert!(matches!(
+        ECDSAAlgorithm::verify_strict(&pub_key, data, &high_s),
+        Err(EcdsaError::HighS)
+    ));
+
+    // R 前多一个 0x00 的非规范DER
+    let mut padded = der.clone();
+    padded[1] += 1;
+    padded[3] += 1;
+    padded.insert(4, 0x00);
+    let padded = general_purpose::STANDARD.encode(&padded);
+    assert!(matches!(
+        ECDSAAlgorithm::verify_strict(&pub_key, data, &padded),
+        Err(EcdsaError::DerExcessLeadingZero)
+    ));
+
+    // 末尾附加多余字节
+    let mut trailing = der.clone();
+    trailing.push(0x01);
+    let trailing = general_purpose::STANDARD.encode(&trailing);
+    assert!(matches!(
+        ECDSAAlgorithm::verify_strict(&pub_key, data, &trailing),
+        Err(EcdsaError::DerInvalidSequence)
+    ));
+
+    // S 为负数
+    let mut negative = der.clone();
+    let s_offset = 6 + negative[3] as usize;
+    negative[s_offset] |= 0x80;
+    let negative = general_purpose::STANDARD.encode(&negative);
+    assert!(matches!(
+        ECDSAAlgorithm::verify_strict(&pub_key, data, &negative),
+        Err(EcdsaError::DerNegativeInteger)
+    ));
+
+    // 过短的签名
+    let short = general_purpose::STANDARD.encode(&der[..6]);
+    assert!(matches!(
+        ECDSAAlgorithm::verify_strict(&pub_key, data, &short),
+        Err(EcdsaError::DerInvalidLength)
+    ));
+
+    // 严格模式下签名不匹配仍返回 false
+    assert!(!ECDSAAlgorithm::verify_strict(&pub_key, b"tampered message", &signature).unwrap());
+    let message_hash = BaseAlgorithm::hash(HashAlgorithm::Sha256, data);
+    assert!(ECDSAAlgorithm::verify_prehash_strict(&pub_key, &message_hash, &signature).unwrap());
+}
+
+#[test]
+fn test_wif_vectors() {
+    // Bitcoin Wiki 中的 WIF 示例
+    let key = general_purpose::STANDARD.encode(hex_bytes(
+        "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d",
+    ));
+    let wif = ECDSAAlgorithm::private_key_to_wif(&key, false, WifNetwork::Mainnet).unwrap();
+    assert_eq!(wif, "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ");
+    assert_eq!(
+        ECDSAAlgorithm::private_key_from_wif(&wif).unwrap(),
+        (key, false, WifNetwork::Mainnet)
+    );
+
+    // 私钥为1的压缩与非压缩 WIF
+    let one = general_purpose::STANDARD.encode(hex_bytes(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ));
+    assert_eq!(
+        ECDSAAlgorithm::private_key_to_wif(&one, true, WifNetwork::Mainnet).unwrap(),
+        "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn"
+    );
+    assert_eq!(
+        ECDSAAlgorithm::private_key_to_wif(&one, false, WifNetwork::Mainnet).unwrap(),
+        "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf"
+    );
+}
+
+#[test]
+fn test_wif_round_trip_and_errors() {
+    let key = ECDSAAlgorithm::generate_private_key();
+    let wif = ECDSAAlgorithm::private_key_to_wif(&key, true, WifNetwork::Testnet).unwrap();
+    assert!(wif.starts_with('c'));
+    let (decoded, compressed, network) = ECDSAAlgorithm::private_key_from_wif(&wif).unwrap();
+    assert_eq!(decoded, key);
+    assert!(compressed);
+    assert_eq!(network, WifNetwork::Testnet);
+    // 导入后派生的公钥应与压缩标志一致
+    assert_eq!(
+        ECDSAAlgorithm::generate_public_key(&decoded, compressed).unwrap(),
+        ECDSAAlgorithm::generate_public_key(&key, true).unwrap()
+    );
+
+    // 篡改最后一个字符导致校验和错误
+    let mut tampered = wif.clone();
+    let last = tampered.pop().unwrap();
+    tampered.push(if last == '1' { '2' } else { '1' });
+    assert!(matches!(
+        ECDSAAlgorithm::private_key_from_wif(&tampered),
+        Err(EcdsaError::InvalidWif(_))
+    ));
+
+    // 地址不是 WIF：前缀和长度均不符
+    assert!(matches!(
+        ECDSAAlgorithm::private_key_from_wif("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"),
+        Err(EcdsaError::InvalidWif(_))
+    ));
+}
@@ -0,0 +1,173 @@
+use base64::Engine as _;
+use blockchain_rs::common::algorithm::base_58_algorithm::Base58Version;
+use blockchain_rs::common::algorithm::ecdsa_algorithm::{AddressKind, ECDSAAlgorithm, EcdsaError};
+use blockchain_rs::common::algorithm::ecdsa_types::{Address, PrivateKey, PublicKey, Signature};
+
+/// 私钥为1时对应生成元G / Private key 1 maps to the generator G
+const ONE_HEX: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+const G_COMPRESSED_HEX: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// 私钥为1的主网 P2PKH 地址 / Mainnet P2PKH address of private key 1
+const G_ADDRESS: &str = "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH";
+
+#[test]
+fn test_private_key_encodings() {
+    let key = PrivateKey::from_hex(ONE_HEX).unwrap();
+    assert_eq!(key.to_hex(), ONE_HEX);
+    assert_eq!(key.to_bytes()[31], 1);
+    assert_eq!(PrivateKey::from_base64(&key.to_base64()).unwrap(), key);
+    assert_eq!(key.to_base64().parse::<PrivateKey>().unwrap(), key);
+
+    assert!(matches!(
+        PrivateKey::from_hex("zz"),
+        Err(EcdsaError::HexError)
+    ));
+    assert!(matches!(
+        PrivateKey::from_bytes(&[0u8; 32]),
+        Err(EcdsaError::KeyGenerationError)
+    ));
+}
+
+#[test]
+fn test_private_key_debug_is_redacted() {
+    let key = PrivateKey::from_hex(ONE_HEX).unwrap();
+    let debug = format!("{:?}", key);
+    assert_eq!(debug, "PrivateKey(<redacted>)");
+    assert!(!debug.contains(&key.to_hex()));
+}
+
+#[test]
+fn test_public_key_and_address() {
+    let public_key = PrivateKey::from_hex(ONE_HEX).unwrap().public_key();
+    assert!(public_key.is_compressed());
+    assert_eq!(public_key.to_hex(), G_COMPRESSED_HEX);
+    assert_eq!(public_key.with_compression(false).to_bytes().len(), 65);
+    assert_eq!(
+        PublicKey::from_hex(&public_key.with_compression(false).to_hex())
+            .unwrap()
+            .with_compression(true),
+        public_key
+    );
+
+    let address = public_key.address();
+    assert_eq!(address.as_str(), G_ADDRESS);
+    assert_eq!(address.to_bytes().len(), 20);
+    assert_eq!(G_ADDRESS.parse::<Address>().unwrap(), address);
+    assert!(matches!(
+        address.kind(),
+        AddressKind::Base58 {
+            version: Base58Version::Mainnet,
+            ..
+        }
+    ));
+
+    let segwit = public_key.segwit_address("bc").unwrap();
+    assert_eq!(segwit.to_bytes(), address.to_bytes());
+    assert!("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMX"
+        .parse::<Address>()
+        .is_err());
+}
+
+#[test]
+fn test_sign_and_verify() {
+    let private_key = PrivateKey::generate();
+    let public_key = private_key.public_key();
+    let data = b"typed message";
+
+    let signature = private_key.sign(data);
+    assert!(public_key.verify(data, &signature));
+    assert!(!public_key.verify(b"other message", &signature));
+
+    assert_eq!(Signature::from_der(&signature.to_der()).unwrap(), signature);
+    assert_eq!(
+        Signature::from_bytes(&signature.to_bytes()).unwrap(),
+        signature
+    );
+    assert_eq!(Signature::from_hex(&signature.to_hex()).unwrap(), signature);
+    assert_eq!(
+        signature.to_string().parse::<Signature>().unwrap(),
+        signature
+    );
+
+    assert!(matches!(
+        private_key.sign_prehash(&[0u8; 31]),
+        Err(EcdsaError::InvalidHashLength(31))
+    ));
+}
+
+#[test]
+fn test_signature_rejects_high_s() {
+    let signature = PrivateKey::generate().sign(b"typed message");
+    let parsed = k256::ecdsa::Signature::try_from(&signature.to_bytes()[..]).unwrap();
+    // s 替换为 n - s 得到同样有效的高S值签名 / n - s gives an equally valid high-S signature
+    let high_s =
+        k256::ecdsa::Signature::from_scalars(parsed.r().to_bytes(), (-*parsed.s()).to_bytes())
+            .unwrap();
+
+    assert!(matches!(
+        Signature::from_der(high_s.to_der().as_bytes()),
+        Err(EcdsaError::HighS)
+    ));
+    assert!(matches!(
+        Signature::from_bytes(high_s.as_ref()),
+        Err(EcdsaError::HighS)
+    ));
+    let encoded = base64::engine::general_purpose::STANDARD.encode(high_s.to_der().as_bytes());
+    assert!(matches!(
+        encoded.parse::<Signature>(),
+        Err(EcdsaError::HighS)
+    ));
+}
+
+#[test]
+fn test_string_wrappers_match_types() {
+    let private_key = PrivateKey::generate();
+    let encoded = private_key.to_base64();
+    let data = b"wrapper";
+
+    assert_eq!(
+        ECDSAAlgorithm::generate_public_key(&encoded, true).unwrap(),
+        private_key.public_key().to_string()
+    );
+    // RFC 6979 确定性签名 / RFC 6979 signatures are deterministic
+    assert_eq!(
+        ECDSAAlgorithm::sign(&encoded, data).unwrap(),
+        private_key.sign(data).to_string()
+    );
+    let public_key: PublicKey = ECDSAAlgorithm::generate_public_key(&encoded, false)
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(!public_key.is_compressed());
+    assert_eq!(
+        public_key.address().to_string(),
+        ECDSAAlgorithm::get_address(&public_key.to_string()).unwrap()
+    );
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let private_key = PrivateKey::from_hex(ONE_HEX).unwrap();
+    let public_key = private_key.public_key();
+    let signature = private_key.sign(b"serde");
+    let address = public_key.address();
+
+    let json = serde_json::to_string(&private_key).unwrap();
+    assert_eq!(json, format!("\"{}\"", private_key.to_base64()));
+    assert_eq!(
+        serde_json::from_str::<PrivateKey>(&json).unwrap(),
+        private_key
+    );
+
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key
+    );
+
+    let json = serde_json::to_string(&signature).unwrap();
+    assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(json, format!("\"{}\"", G_ADDRESS));
+    assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+}
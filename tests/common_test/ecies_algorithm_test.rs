@@ -0,0 +1,92 @@
+use base64::{engine::general_purpose, Engine as _};
+use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use blockchain_rs::common::algorithm::ecies_algorithm::{
+    EciesAlgorithm, EciesError, EPHEMERAL_KEY_LEN,
+};
+
+fn key_pair(compressed: bool) -> (String, String) {
+    let private_key = ECDSAAlgorithm::generate_private_key();
+    let public_key = ECDSAAlgorithm::generate_public_key(&private_key, compressed).unwrap();
+    (private_key, public_key)
+}
+
+#[test]
+fn test_shared_secret_agreement() {
+    let (alice_private, alice_public) = key_pair(true);
+    let (bob_private, bob_public) = key_pair(false);
+
+    let alice_secret = ECDSAAlgorithm::shared_secret(&alice_private, &bob_public).unwrap();
+    let bob_secret = ECDSAAlgorithm::shared_secret(&bob_private, &alice_public).unwrap();
+    assert_eq!(alice_secret, bob_secret);
+    assert_eq!(
+        general_purpose::STANDARD
+            .decode(&alice_secret)
+            .unwrap()
+            .len(),
+        32
+    );
+
+    let (carol_private, _) = key_pair(true);
+    assert_ne!(
+        ECDSAAlgorithm::shared_secret(&carol_private, &bob_public).unwrap(),
+        alice_secret
+    );
+}
+
+#[test]
+fn test_encrypt_decrypt_round_trip() {
+    for compressed in [true, false] {
+        let (private_key, public_key) = key_pair(compressed);
+        for data in [&b""[..], b"private transaction", &[0xab; 1000]] {
+            let ciphertext = EciesAlgorithm::encrypt(&public_key, data).unwrap();
+            assert!(ciphertext.len() > EPHEMERAL_KEY_LEN + data.len());
+            assert_eq!(
+                EciesAlgorithm::decrypt(&private_key, &ciphertext).unwrap(),
+                data
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ephemeral_keys_differ() {
+    let (_, public_key) = key_pair(true);
+    let first = EciesAlgorithm::encrypt(&public_key, b"handshake").unwrap();
+    let second = EciesAlgorithm::encrypt(&public_key, b"handshake").unwrap();
+    assert_ne!(first[..EPHEMERAL_KEY_LEN], second[..EPHEMERAL_KEY_LEN]);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_decrypt_rejects_wrong_key_and_tampering() {
+    let (private_key, public_key) = key_pair(true);
+    let ciphertext = EciesAlgorithm::encrypt(&public_key, b"secret payload").unwrap();
+
+    let (other_private, _) = key_pair(true);
+    assert!(matches!(
+        EciesAlgorithm::decrypt(&other_private, &ciphertext),
+        Err(EciesError::DecryptionFailed)
+    ));
+
+    // 篡改密文最后一个字节
+    let mut tampered = ciphertext.clone();
+    *tampered.last_mut().unwrap() ^= 0x01;
+    assert!(matches!(
+        EciesAlgorithm::decrypt(&private_key, &tampered),
+        Err(EciesError::DecryptionFailed)
+    ));
+
+    // 替换临时公钥：MAC 覆盖临时公钥，解密失败
+    let (_, substitute) = key_pair(true);
+    let mut swapped = general_purpose::STANDARD.decode(&substitute).unwrap();
+    swapped.extend_from_slice(&ciphertext[EPHEMERAL_KEY_LEN..]);
+    assert!(matches!(
+        EciesAlgorithm::decrypt(&private_key, &swapped),
+        Err(EciesError::DecryptionFailed)
+    ));
+
+    assert!(matches!(
+        EciesAlgorithm::decrypt(&private_key, &ciphertext[..10]),
+        Err(EciesError::InvalidCiphertext(_))
+    ));
+}
@@ -0,0 +1,98 @@
+use blockchain_rs::common::algorithm::kdf_algorithm::{
+    KDFAlgorithm, KdfParams, PassphraseCiphertext, MAX_DKLEN, MAX_PBKDF2_ITERATIONS,
+    MAX_SCRYPT_N, MAX_SCRYPT_P, MAX_SCRYPT_R,
+};
+use super::hex_bytes;
+
+#[test]
+fn test_pbkdf2_vector() {
+    // RFC 7914 第11节 PBKDF2-HMAC-SHA256 测试向量
+    let params = KdfParams::Pbkdf2 {
+        c: 1,
+        dklen: 64,
+        prf: "hmac-sha256".to_string(),
+        salt: b"salt".to_vec(),
+    };
+    let key = KDFAlgorithm::derive_key(b"passwd", &params).unwrap();
+    assert_eq!(
+        key,
+        hex_bytes("55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783")
+    );
+}
+
+#[test]
+fn test_scrypt_vector() {
+    // RFC 7914 第12节 scrypt 测试向量
+    let params = KdfParams::Scrypt {
+        n: 1024,
+        r: 8,
+        p: 16,
+        dklen: 64,
+        salt: b"NaCl".to_vec(),
+    };
+    let key = KDFAlgorithm::derive_key(b"password", &params).unwrap();
+    assert_eq!(
+        key,
+        hex_bytes("fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640")
+    );
+}
+
+#[test]
+fn test_invalid_params() {
+    let params = KdfParams::Scrypt {
+        n: 1000,
+        r: 8,
+        p: 1,
+        dklen: 32,
+        salt: b"salt".to_vec(),
+    };
+    assert!(KDFAlgorithm::derive_key(b"password", &params).is_err());
+}
+
+#[test]
+fn test_param_limits() {
+    let scrypt = |n, r, p, dklen| KdfParams::Scrypt {
+        n,
+        r,
+        p,
+        dklen,
+        salt: b"salt".to_vec(),
+    };
+    // 推荐参数在上限之内
+    assert!(KDFAlgorithm::check_params(&KdfParams::default_scrypt().unwrap()).is_ok());
+    assert!(KDFAlgorithm::check_params(&KdfParams::default_pbkdf2().unwrap()).is_ok());
+    assert!(KDFAlgorithm::check_params(&scrypt(MAX_SCRYPT_N, 8, 1, 32)).is_ok());
+
+    // 构造的巨大参数被拒绝而不是溢出或耗尽内存
+    assert!(KDFAlgorithm::derive_key(b"password", &scrypt(1 << 63, u32::MAX, u32::MAX, 32)).is_err());
+    assert!(KDFAlgorithm::check_params(&scrypt(MAX_SCRYPT_N * 2, 8, 1, 32)).is_err());
+    assert!(KDFAlgorithm::check_params(&scrypt(1024, MAX_SCRYPT_R + 1, 1, 32)).is_err());
+    assert!(KDFAlgorithm::check_params(&scrypt(1024, 8, MAX_SCRYPT_P + 1, 32)).is_err());
+    // 每项都在上限内但内存需求超过 MAX_SCRYPT_MEMORY
+    assert!(KDFAlgorithm::check_params(&scrypt(MAX_SCRYPT_N, MAX_SCRYPT_R, 1, 32)).is_err());
+    assert!(KDFAlgorithm::check_params(&scrypt(1024, 8, 1, MAX_DKLEN + 1)).is_err());
+
+    let pbkdf2 = KdfParams::Pbkdf2 {
+        c: MAX_PBKDF2_ITERATIONS + 1,
+        dklen: 32,
+        prf: "hmac-sha256".to_string(),
+        salt: b"salt".to_vec(),
+    };
+    assert!(KDFAlgorithm::derive_key(b"password", &pbkdf2).is_err());
+}
+
+#[test]
+fn test_passphrase_round_trip() {
+    let params = KdfParams::scrypt(1024, 8, 1, 32).unwrap();
+    let encrypted =
+        KDFAlgorithm::encrypt_with_passphrase(b"correct horse", params, b"private key").unwrap();
+
+    // 序列化后仅凭口令即可解密
+    let json = serde_json::to_string(&encrypted).unwrap();
+    assert!(json.contains("\"kdf\":\"scrypt\""));
+    let parsed: PassphraseCiphertext = serde_json::from_str(&json).unwrap();
+    let decrypted = KDFAlgorithm::decrypt_with_passphrase(b"correct horse", &parsed).unwrap();
+    assert_eq!(decrypted, b"private key");
+
+    assert!(KDFAlgorithm::decrypt_with_passphrase(b"wrong", &parsed).is_err());
+}
@@ -0,0 +1,116 @@
+use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use blockchain_rs::common::algorithm::kdf_algorithm::KdfParams;
+use blockchain_rs::common::keystore::key_store::{KeyStore, KeystoreError, KEYSTORE_VERSION};
+
+/// 测试使用的低开销 scrypt 参数
+fn test_kdf() -> KdfParams {
+    KdfParams::scrypt(1024, 8, 1, 32).unwrap()
+}
+
+#[test]
+fn test_encrypt_decrypt() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let keystore = KeyStore::encrypt(&priv_key, "passphrase", test_kdf()).unwrap();
+    assert_eq!(keystore.version, KEYSTORE_VERSION);
+    assert_eq!(keystore.crypto.cipher, "aes-128-ctr");
+
+    let pub_key = ECDSAAlgorithm::generate_public_key(&priv_key, true).unwrap();
+    assert_eq!(
+        keystore.address,
+        ECDSAAlgorithm::get_address(&pub_key).unwrap()
+    );
+    assert_eq!(*keystore.decrypt("passphrase").unwrap(), priv_key);
+
+    assert!(matches!(
+        keystore.decrypt("wrong"),
+        Err(KeystoreError::InvalidPassphrase)
+    ));
+}
+
+#[test]
+fn test_save_load_and_change_passphrase() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let keystore =
+        KeyStore::encrypt(&priv_key, "old", KdfParams::pbkdf2(1000, 32).unwrap()).unwrap();
+
+    let path = std::env::temp_dir().join(format!("keystore-{}.json", keystore.id));
+    // 已有的宽权限文件被覆盖后权限收紧为 0600
+    std::fs::write(&path, "").unwrap();
+    keystore.save(&path).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    let loaded = KeyStore::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, keystore);
+
+    let changed = loaded.change_passphrase("old", "new", test_kdf()).unwrap();
+    assert_eq!(changed.id, keystore.id);
+    assert_eq!(*changed.decrypt("new").unwrap(), priv_key);
+    assert!(matches!(
+        changed.decrypt("old"),
+        Err(KeystoreError::InvalidPassphrase)
+    ));
+    assert!(matches!(
+        loaded.change_passphrase("wrong", "new", test_kdf()),
+        Err(KeystoreError::InvalidPassphrase)
+    ));
+}
+
+#[test]
+fn test_corrupted_keystore() {
+    let priv_key = ECDSAAlgorithm::generate_private_key();
+    let keystore = KeyStore::encrypt(&priv_key, "passphrase", test_kdf()).unwrap();
+    let json = keystore.to_json().unwrap();
+
+    // 非法 JSON 与缺失字段
+    assert!(matches!(
+        KeyStore::from_json("{not json"),
+        Err(KeystoreError::Corrupted(_))
+    ));
+    assert!(matches!(
+        KeyStore::from_json("{\"version\":1}"),
+        Err(KeystoreError::Corrupted(_))
+    ));
+
+    // 版本不支持，包括以太坊 v3 keystore
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["version"] = serde_json::json!(3);
+    assert!(matches!(
+        KeyStore::from_json(&value.to_string()),
+        Err(KeystoreError::UnsupportedVersion(3))
+    ));
+
+    // 构造的超大 KDF 参数在派生前被拒绝
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["crypto"]["kdfparams"]["n"] = serde_json::json!(1u64 << 40);
+    assert!(matches!(
+        KeyStore::from_json(&value.to_string()),
+        Err(KeystoreError::Corrupted(_))
+    ));
+
+    // 密文被改动与口令错误无法区分
+    let mut tampered = keystore.clone();
+    tampered.crypto.ciphertext[0] ^= 1;
+    assert!(matches!(
+        tampered.decrypt("passphrase"),
+        Err(KeystoreError::InvalidPassphrase)
+    ));
+
+    // 地址被篡改
+    let mut tampered = keystore.clone();
+    tampered.address = "1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string();
+    assert!(matches!(
+        tampered.decrypt("passphrase"),
+        Err(KeystoreError::Corrupted(_))
+    ));
+
+    // 文件不存在
+    assert!(matches!(
+        KeyStore::load("/nonexistent/keystore.json"),
+        Err(KeystoreError::Io(_))
+    ));
+}
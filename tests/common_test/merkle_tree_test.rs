@@ -0,0 +1,124 @@
+use blockchain_rs::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use blockchain_rs::common::merkle::merkle_tree::{
+    MerkleError, MerkleMode, MerkleProof, MerkleTree,
+};
+use super::hex_bytes;
+
+const SHA256: HashAlgorithm = HashAlgorithm::Sha256;
+
+/// 将区块浏览器显示的逆序十六进制转换为内部字节序
+fn reversed_hex(hex: &str) -> Vec<u8> {
+    let mut bytes = hex_bytes(hex);
+    bytes.reverse();
+    bytes
+}
+
+#[test]
+fn test_bitcoin_block_100000() {
+    let txids = [
+        "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+        "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+        "6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4",
+        "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d",
+    ];
+    let leaves: Vec<Vec<u8>> = txids.iter().map(|txid| reversed_hex(txid)).collect();
+    let tree = MerkleTree::new(SHA256, MerkleMode::Bitcoin, leaves.clone()).unwrap();
+    assert_eq!(
+        tree.root(),
+        reversed_hex("f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766")
+    );
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = tree.proof(index).unwrap();
+        assert_eq!(proof.path.len(), 2);
+        assert!(tree.verify(leaf, &proof));
+        assert!(proof.verify(SHA256, MerkleMode::Bitcoin, leaf, tree.root()));
+    }
+}
+
+#[test]
+fn test_single_leaf_and_odd_duplication() {
+    let single = MerkleTree::new(SHA256, MerkleMode::Bitcoin, vec![vec![7u8; 32]]).unwrap();
+    assert_eq!(single.root(), &[7u8; 32]);
+    assert!(single.proof(0).unwrap().path.is_empty());
+
+    // 三个叶子：根 = H(H(a, b), H(c, c))
+    let tree = MerkleTree::from_data(SHA256, MerkleMode::Bitcoin, &["a", "b", "c"]).unwrap();
+    let leaf = |data: &str| MerkleTree::hash_leaf(SHA256, MerkleMode::Bitcoin, data.as_bytes());
+    let node = |l: &[u8], r: &[u8]| MerkleTree::hash_node(SHA256, MerkleMode::Bitcoin, l, r);
+    let ab = node(&leaf("a"), &leaf("b"));
+    let cc = node(&leaf("c"), &leaf("c"));
+    assert_eq!(tree.root(), node(&ab, &cc));
+    assert_eq!(leaf("a"), BaseAlgorithm::hash_twice(SHA256, b"a"));
+
+    let proof = tree.proof(2).unwrap();
+    assert_eq!(proof.path[0].hash, leaf("c"));
+    assert!(tree.verify(&leaf("c"), &proof));
+}
+
+#[test]
+fn test_domain_separated_mode() {
+    let items: Vec<String> = (0..7).map(|i| format!("tx-{}", i)).collect();
+    let tree = MerkleTree::from_data(SHA256, MerkleMode::DomainSeparated, &items).unwrap();
+    for (index, item) in items.iter().enumerate() {
+        let leaf = MerkleTree::hash_leaf(SHA256, MerkleMode::DomainSeparated, item.as_bytes());
+        let proof = tree.proof(index).unwrap();
+        assert!(tree.verify(&leaf, &proof));
+    }
+    // 第7个叶子在第一层被提升，路径比其他叶子短
+    assert!(tree.proof(6).unwrap().path.len() < tree.proof(0).unwrap().path.len());
+
+    // 比特币模式下末尾重复叶子得到相同的根，域分隔模式下不同
+    let duplicated = ["a", "b", "c", "c"];
+    let bitcoin = |items: &[&str]| {
+        MerkleTree::from_data(SHA256, MerkleMode::Bitcoin, items)
+            .unwrap()
+            .root()
+            .to_vec()
+    };
+    let safe = |items: &[&str]| {
+        MerkleTree::from_data(SHA256, MerkleMode::DomainSeparated, items)
+            .unwrap()
+            .root()
+            .to_vec()
+    };
+    assert_eq!(bitcoin(&duplicated[..3]), bitcoin(&duplicated));
+    assert_ne!(safe(&duplicated[..3]), safe(&duplicated));
+
+    // 内部节点不能冒充叶子
+    let pair = MerkleTree::from_data(SHA256, MerkleMode::DomainSeparated, &["a", "b"]).unwrap();
+    let empty_proof = MerkleProof {
+        leaf_index: 0,
+        path: Vec::new(),
+    };
+    let forged = MerkleTree::hash_leaf(SHA256, MerkleMode::DomainSeparated, pair.root());
+    assert!(!pair.verify(&forged, &empty_proof));
+}
+
+#[test]
+fn test_invalid_proofs_and_errors() {
+    let tree = MerkleTree::from_data(SHA256, MerkleMode::Bitcoin, &["a", "b", "c", "d"]).unwrap();
+    let leaf = MerkleTree::hash_leaf(SHA256, MerkleMode::Bitcoin, b"a");
+    let mut proof = tree.proof(0).unwrap();
+
+    assert!(!tree.verify(
+        &MerkleTree::hash_leaf(SHA256, MerkleMode::Bitcoin, b"x"),
+        &proof
+    ));
+    assert!(!proof.verify(SHA256, MerkleMode::DomainSeparated, &leaf, tree.root()));
+
+    let json = serde_json::to_string(&proof).unwrap();
+    assert_eq!(serde_json::from_str::<MerkleProof>(&json).unwrap(), proof);
+
+    proof.path[1].hash[0] ^= 0x01;
+    assert!(!tree.verify(&leaf, &proof));
+
+    assert_eq!(
+        tree.proof(4),
+        Err(MerkleError::IndexOutOfRange { index: 4, len: 4 })
+    );
+    assert_eq!(
+        MerkleTree::new(SHA256, MerkleMode::Bitcoin, Vec::new()),
+        Err(MerkleError::EmptyTree)
+    );
+}
@@ -0,0 +1,103 @@
+use blockchain_rs::common::algorithm::bip32_algorithm::{Bip32Network, ExtendedPrivateKey};
+use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use blockchain_rs::common::mnemonic::mnemonic_code::{
+    Language, Mnemonic, MnemonicError, WORD_COUNTS,
+};
+use super::hex_bytes;
+
+#[test]
+fn test_bip39_vectors() {
+    // BIP-39 官方测试向量，口令为 "TREZOR"
+    let vectors = [
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+        ),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+            "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+        ),
+    ];
+    for (entropy, phrase, seed) in vectors {
+        let mnemonic = Mnemonic::from_entropy(&hex_bytes(entropy), Language::English).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+        let parsed = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert_eq!(parsed.entropy(), hex_bytes(entropy).as_slice());
+        assert_eq!(parsed.to_seed("TREZOR").unwrap().to_vec(), hex_bytes(seed));
+    }
+
+    // 种子生成的私钥即 BIP-32 主私钥
+    let mnemonic = Mnemonic::from_entropy(&[0u8; 16], Language::English).unwrap();
+    let seed = mnemonic.to_seed("TREZOR").unwrap();
+    let master = ExtendedPrivateKey::from_seed(&seed, Bip32Network::Mainnet).unwrap();
+    assert_eq!(
+        master.to_string(),
+        "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF"
+    );
+    assert_eq!(
+        ECDSAAlgorithm::generate_private_key_from_seed(&seed).unwrap(),
+        master.private_key()
+    );
+}
+
+#[test]
+fn test_generate_and_recover() {
+    for language in [Language::English, Language::ChineseSimplified] {
+        assert_eq!(language.word_list().len(), 2048);
+        for word_count in WORD_COUNTS {
+            let mnemonic = Mnemonic::generate(word_count, language).unwrap();
+            assert_eq!(mnemonic.words().len(), word_count);
+
+            // 从手写备份恢复出相同的种子和私钥
+            let recovered = Mnemonic::from_phrase(&mnemonic.phrase(), language).unwrap();
+            assert_eq!(recovered, mnemonic);
+            let seed = recovered.to_seed("").unwrap();
+            assert_eq!(seed, mnemonic.to_seed("").unwrap());
+            assert_ne!(seed, mnemonic.to_seed("passphrase").unwrap());
+        }
+    }
+    assert_eq!(Language::ChineseSimplified.word_list()[0], "的");
+}
+
+#[test]
+fn test_invalid_phrases() {
+    assert_eq!(
+        Mnemonic::generate(13, Language::English),
+        Err(MnemonicError::InvalidWordCount(13))
+    );
+    assert_eq!(
+        Mnemonic::from_entropy(&[0u8; 15], Language::English),
+        Err(MnemonicError::InvalidEntropyLength(15))
+    );
+    assert_eq!(
+        Mnemonic::from_phrase("abandon abandon abandon", Language::English),
+        Err(MnemonicError::InvalidWordCount(3))
+    );
+    // 最后一个单词错误导致校验和不匹配
+    assert_eq!(
+        Mnemonic::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+            Language::English
+        ),
+        Err(MnemonicError::InvalidChecksum)
+    );
+    assert_eq!(
+        Mnemonic::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon bitcoin",
+            Language::English
+        ),
+        Err(MnemonicError::UnknownWord("bitcoin".to_string()))
+    );
+    // 单词表语言不匹配
+    let chinese = Mnemonic::generate(12, Language::ChineseSimplified).unwrap();
+    assert!(Mnemonic::from_phrase(&chinese.phrase(), Language::English).is_err());
+    // Debug 输出不包含助记词
+    assert!(!format!("{:?}", chinese).contains(chinese.words()[0]));
+}
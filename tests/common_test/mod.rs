@@ -5,8 +5,47 @@ pub mod base_58_algorithm_test;
 #[cfg(test)]
 pub mod base_algorithm_test;
 #[cfg(test)]
+pub mod bech32_algorithm_test;
+#[cfg(test)]
+pub mod bip32_algorithm_test;
+#[cfg(test)]
+pub mod clock_test;
+#[cfg(test)]
+pub mod cron_schedule_test;
+#[cfg(test)]
+pub mod des3_algorithm_test;
+#[cfg(test)]
 pub mod ecdsa_algorithm_test;
 #[cfg(test)]
+pub mod ecdsa_types_test;
+#[cfg(test)]
+pub mod ecies_algorithm_test;
+#[cfg(test)]
 pub mod exception_test;
 #[cfg(test)]
+pub mod kdf_algorithm_test;
+#[cfg(test)]
+pub mod key_store_test;
+#[cfg(test)]
+pub mod merkle_tree_test;
+#[cfg(test)]
+pub mod mnemonic_code_test;
+#[cfg(test)]
+pub mod multisig_algorithm_test;
+#[cfg(test)]
+pub mod schnorr_algorithm_test;
+#[cfg(test)]
+pub mod signature_scheme_test;
+#[cfg(test)]
+pub mod sparse_merkle_tree_test;
+#[cfg(test)]
 pub mod timer_manager_test;
+
+/// 将十六进制字符串转换为字节数组，供各测试模块共用
+#[cfg(test)]
+pub fn hex_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
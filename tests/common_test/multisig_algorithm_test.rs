@@ -0,0 +1,189 @@
+use base64::{engine::general_purpose, Engine as _};
+use blockchain_rs::common::algorithm::base_58_algorithm::{Base58Algorithm, Base58Version};
+use blockchain_rs::common::algorithm::base_algorithm::{BaseAlgorithm, HashAlgorithm};
+use blockchain_rs::common::algorithm::ecdsa_algorithm::{AddressKind, ECDSAAlgorithm};
+use blockchain_rs::common::algorithm::multisig_algorithm::{MultisigAlgorithm, MultisigError};
+use super::hex_bytes;
+
+/// 私钥1、2、3对应的压缩公钥 G、2G、3G / Compressed public keys G, 2G and 3G of private keys 1, 2, 3
+const PUBLIC_KEYS_HEX: [&str; 3] = [
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+    "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+];
+
+/// 生成N个私钥及其压缩公钥
+fn key_pairs(n: usize) -> Vec<(String, String)> {
+    (0..n)
+        .map(|_| {
+            let private_key = ECDSAAlgorithm::generate_private_key();
+            let public_key = ECDSAAlgorithm::generate_public_key(&private_key, true).unwrap();
+            (private_key, public_key)
+        })
+        .collect()
+}
+
+#[test]
+fn test_redeem_script_layout_and_address() {
+    let public_keys: Vec<String> = PUBLIC_KEYS_HEX
+        .iter()
+        .map(|hex| general_purpose::STANDARD.encode(hex_bytes(hex)))
+        .collect();
+    let refs: Vec<&str> = public_keys.iter().map(String::as_str).collect();
+
+    let script = MultisigAlgorithm::create_redeem_script(2, &refs).unwrap();
+    let expected = format!(
+        "5221{}21{}21{}53ae",
+        PUBLIC_KEYS_HEX[0], PUBLIC_KEYS_HEX[1], PUBLIC_KEYS_HEX[2]
+    );
+    assert_eq!(
+        general_purpose::STANDARD.decode(&script).unwrap(),
+        hex_bytes(&expected)
+    );
+
+    let (threshold, parsed) = MultisigAlgorithm::parse_redeem_script(&script).unwrap();
+    assert_eq!(threshold, 2);
+    assert_eq!(parsed, public_keys);
+
+    // SHA-256 → RIPEMD-160 → Base58Check(0x05)
+    let address = MultisigAlgorithm::get_address(&script).unwrap();
+    let hash = BaseAlgorithm::hash(
+        HashAlgorithm::Ripemd160,
+        &BaseAlgorithm::hash(HashAlgorithm::Sha256, &hex_bytes(&expected)),
+    );
+    assert_eq!(address, Base58Algorithm::encode_check(0x05, &hash));
+    assert!(address.starts_with('3'));
+    assert_eq!(
+        ECDSAAlgorithm::parse_address(&address).unwrap(),
+        AddressKind::Base58 {
+            version: Base58Version::P2sh,
+            hash,
+        }
+    );
+
+    let testnet =
+        MultisigAlgorithm::get_address_with_version(&script, Base58Version::TestnetP2sh).unwrap();
+    assert!(testnet.starts_with('2'));
+}
+
+#[test]
+fn test_two_of_three_verification() {
+    let pairs = key_pairs(3);
+    let public_keys: Vec<&str> = pairs.iter().map(|(_, p)| p.as_str()).collect();
+    let script = MultisigAlgorithm::create_redeem_script(2, &public_keys).unwrap();
+    let data = b"treasury transfer";
+    let signatures: Vec<String> = pairs
+        .iter()
+        .map(|(k, _)| ECDSAAlgorithm::sign(k, data).unwrap())
+        .collect();
+
+    // 任意两个签名，顺序不限
+    assert!(MultisigAlgorithm::verify(&script, data, &[&signatures[2], &signatures[0]]).unwrap());
+    assert!(MultisigAlgorithm::verify(&script, data, &[&signatures[1], &signatures[2]]).unwrap());
+
+    // 一个签名不够，同一签名重复提交也不够
+    assert!(!MultisigAlgorithm::verify(&script, data, &[&signatures[0]]).unwrap());
+    assert!(!MultisigAlgorithm::verify(&script, data, &[&signatures[0], &signatures[0]]).unwrap());
+
+    // 外部密钥的签名不计数
+    let (outsider, _) = &key_pairs(1)[0];
+    let foreign = ECDSAAlgorithm::sign(outsider, data).unwrap();
+    assert!(!MultisigAlgorithm::verify(&script, data, &[&signatures[0], &foreign]).unwrap());
+
+    // 高S值签名不计数，无法延展审批签名
+    let der = general_purpose::STANDARD.decode(&signatures[1]).unwrap();
+    let parsed = k256::ecdsa::Signature::from_der(&der).unwrap();
+    let high_s =
+        k256::ecdsa::Signature::from_scalars(parsed.r().to_bytes(), (-*parsed.s()).to_bytes())
+            .unwrap();
+    let high_s = general_purpose::STANDARD.encode(high_s.to_der().as_bytes());
+    assert!(!MultisigAlgorithm::verify(&script, data, &[&signatures[0], &high_s]).unwrap());
+
+    // 无效签名在前也不影响有效的审批，结果与签名顺序无关
+    for bad in [high_s.as_str(), "AAAA", "not base64!"] {
+        assert!(
+            MultisigAlgorithm::verify(&script, data, &[bad, &signatures[0], &signatures[1]])
+                .unwrap()
+        );
+        assert!(
+            MultisigAlgorithm::verify(&script, data, &[&signatures[0], &signatures[1], bad])
+                .unwrap()
+        );
+    }
+
+    // 数据被篡改
+    assert!(
+        !MultisigAlgorithm::verify(&script, b"tampered", &[&signatures[0], &signatures[1]])
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_parameters() {
+    let pairs = key_pairs(2);
+    let public_keys: Vec<&str> = pairs.iter().map(|(_, p)| p.as_str()).collect();
+
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(3, &public_keys),
+        Err(MultisigError::InvalidThreshold {
+            threshold: 3,
+            keys: 2
+        })
+    ));
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(0, &public_keys),
+        Err(MultisigError::InvalidThreshold { .. })
+    ));
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(1, &[]),
+        Err(MultisigError::InvalidKeyCount(0))
+    ));
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(1, &[public_keys[0], public_keys[0]]),
+        Err(MultisigError::DuplicatePublicKey(1))
+    ));
+
+    // 同一公钥的压缩与非压缩形式也是重复 / Compressed and uncompressed forms of one key are duplicates
+    let uncompressed_first = ECDSAAlgorithm::generate_public_key(&pairs[0].0, false).unwrap();
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(
+            2,
+            &[public_keys[0], public_keys[1], &uncompressed_first]
+        ),
+        Err(MultisigError::DuplicatePublicKey(2))
+    ));
+
+    // 手工构造含重复公钥的脚本 / Hand-built script repeating a key
+    let compressed = general_purpose::STANDARD.decode(public_keys[0]).unwrap();
+    let uncompressed = general_purpose::STANDARD
+        .decode(&uncompressed_first)
+        .unwrap();
+    let mut repeated = vec![0x52, 33];
+    repeated.extend_from_slice(&compressed);
+    repeated.push(65);
+    repeated.extend_from_slice(&uncompressed);
+    repeated.extend_from_slice(&[0x52, 0xae]);
+    assert!(matches!(
+        MultisigAlgorithm::parse_redeem_script(&general_purpose::STANDARD.encode(repeated)),
+        Err(MultisigError::DuplicatePublicKey(1))
+    ));
+
+    // 16个非压缩公钥超过520字节
+    let uncompressed: Vec<String> = (0..16)
+        .map(|_| {
+            let key = ECDSAAlgorithm::generate_private_key();
+            ECDSAAlgorithm::generate_public_key(&key, false).unwrap()
+        })
+        .collect();
+    let refs: Vec<&str> = uncompressed.iter().map(String::as_str).collect();
+    assert!(matches!(
+        MultisigAlgorithm::create_redeem_script(2, &refs),
+        Err(MultisigError::ScriptTooLarge(_))
+    ));
+
+    let not_multisig = general_purpose::STANDARD.encode([0x76, 0xa9, 0x88, 0xac]);
+    assert!(matches!(
+        MultisigAlgorithm::get_address(&not_multisig),
+        Err(MultisigError::InvalidRedeemScript(_))
+    ));
+}
@@ -0,0 +1,117 @@
+use base64::{engine::general_purpose, Engine as _};
+use blockchain_rs::common::algorithm::schnorr_algorithm::{SchnorrAlgorithm, SchnorrError};
+use super::hex_bytes;
+
+/// 将十六进制字符串转换为BASE64字符串
+fn hex_base64(hex: &str) -> String {
+    general_purpose::STANDARD.encode(hex_bytes(hex))
+}
+
+#[test]
+fn test_bip340_vectors() {
+    // BIP-340 签名测试向量 0 和 1
+    for (secret_key, public_key, aux_rand, message, signature) in [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        ),
+        (
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        ),
+    ] {
+        let priv_key = hex_base64(secret_key);
+        assert_eq!(SchnorrAlgorithm::generate_public_key(&priv_key).unwrap(), hex_base64(public_key));
+
+        let sig = SchnorrAlgorithm::sign_prehash(&priv_key, &hex_bytes(message), &hex_bytes(aux_rand)).unwrap();
+        assert_eq!(sig, hex_base64(signature));
+        assert!(SchnorrAlgorithm::verify_prehash(&hex_base64(public_key), &hex_bytes(message), &sig).unwrap());
+    }
+}
+
+#[test]
+fn test_signature_round_trip() {
+    let data = b"test message";
+    let priv_key = SchnorrAlgorithm::generate_private_key();
+    let pub_key = SchnorrAlgorithm::generate_public_key(&priv_key).unwrap();
+    assert_eq!(
+        general_purpose::STANDARD.decode(&pub_key).unwrap().len(),
+        32
+    );
+
+    let signature = SchnorrAlgorithm::sign(&priv_key, data).unwrap();
+    assert_eq!(
+        general_purpose::STANDARD.decode(&signature).unwrap().len(),
+        64
+    );
+    assert!(SchnorrAlgorithm::verify(&pub_key, data, &signature).unwrap());
+    assert!(!SchnorrAlgorithm::verify(&pub_key, b"tampered message", &signature).unwrap());
+
+    // 辅助随机数不同，签名不同
+    assert_ne!(signature, SchnorrAlgorithm::sign(&priv_key, data).unwrap());
+    assert!(matches!(
+        SchnorrAlgorithm::sign_prehash(&priv_key, data, &[0u8; 32]),
+        Err(SchnorrError::InvalidHashLength(12))
+    ));
+}
+
+#[test]
+fn test_batch_verify() {
+    let messages: [&[u8]; 3] = [b"block 1", b"block 2", b"block 3"];
+    let keys: Vec<(String, String)> = (0..3)
+        .map(|_| {
+            let priv_key = SchnorrAlgorithm::generate_private_key();
+            let pub_key = SchnorrAlgorithm::generate_public_key(&priv_key).unwrap();
+            (priv_key, pub_key)
+        })
+        .collect();
+    let signatures: Vec<String> = keys
+        .iter()
+        .zip(messages)
+        .map(|((priv_key, _), data)| SchnorrAlgorithm::sign(priv_key, data).unwrap())
+        .collect();
+
+    let items: Vec<(&str, &[u8], &str)> = keys
+        .iter()
+        .zip(messages)
+        .zip(&signatures)
+        .map(|(((_, pub_key), data), sig)| (pub_key.as_str(), data, sig.as_str()))
+        .collect();
+    assert!(SchnorrAlgorithm::batch_verify(&items).unwrap());
+    // 空列表没有需要验证的签名
+    assert!(SchnorrAlgorithm::batch_verify(&[]).unwrap());
+
+    // 交换两条消息的签名后批量验证失败
+    let mut swapped = items.clone();
+    swapped[1].2 = signatures[2].as_str();
+    assert!(!SchnorrAlgorithm::batch_verify(&swapped).unwrap());
+
+    // r 不是有效的x-only点或 s 不小于曲线阶时，单个验证与批量验证都返回 false
+    let sig_bytes = general_purpose::STANDARD.decode(&signatures[0]).unwrap();
+    let order = hex_bytes("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+    let bad_point = hex_bytes("4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D");
+    for (r, s) in [(&sig_bytes[..32], &order[..]), (&bad_point[..], &sig_bytes[32..])] {
+        let bad = general_purpose::STANDARD.encode([r, s].concat());
+        assert!(!SchnorrAlgorithm::verify(&keys[0].1, messages[0], &bad).unwrap());
+        let mut invalid = items.clone();
+        invalid[0].2 = bad.as_str();
+        assert!(!SchnorrAlgorithm::batch_verify(&invalid).unwrap());
+        // 无效项位于最后时结果相同
+        invalid.swap(0, 2);
+        assert!(!SchnorrAlgorithm::batch_verify(&invalid).unwrap());
+    }
+
+    // 编码错误返回 Err
+    let mut malformed = items.clone();
+    malformed[1].2 = "AAAA";
+    assert!(matches!(
+        SchnorrAlgorithm::batch_verify(&malformed),
+        Err(SchnorrError::InvalidSignature)
+    ));
+}
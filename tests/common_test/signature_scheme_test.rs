@@ -0,0 +1,110 @@
+use base64::{engine::general_purpose, Engine as _};
+use blockchain_rs::common::algorithm::base_58_algorithm::Base58Version;
+use blockchain_rs::common::algorithm::ecdsa_algorithm::ECDSAAlgorithm;
+use blockchain_rs::common::algorithm::ed25519_algorithm::Ed25519Algorithm;
+use blockchain_rs::common::algorithm::signature_scheme::SignatureScheme;
+use blockchain_rs::common::algorithm::sm2_algorithm::Sm2Algorithm;
+use super::hex_bytes;
+
+/// 与具体算法无关的签名流程，`prefix` 为地址的首字符
+fn round_trip<S: SignatureScheme>(prefix: char) {
+    let data = b"test message";
+    let priv_key = S::generate_private_key();
+    let pub_key = S::derive_public_key(&priv_key).unwrap();
+
+    let signature = S::sign(&priv_key, data).unwrap();
+    assert!(
+        S::verify(&pub_key, data, &signature).unwrap(),
+        "{}",
+        S::NAME
+    );
+    assert!(
+        !S::verify(&pub_key, b"tampered message", &signature).unwrap(),
+        "{}",
+        S::NAME
+    );
+
+    // 其他密钥无法验证该签名
+    let other_pub = S::derive_public_key(&S::generate_private_key()).unwrap();
+    assert!(
+        !S::verify(&other_pub, data, &signature).unwrap(),
+        "{}",
+        S::NAME
+    );
+
+    let address = S::address(&pub_key).unwrap();
+    assert!(address.starts_with(prefix), "{}", S::NAME);
+    assert_ne!(address, S::address(&other_pub).unwrap());
+}
+
+#[test]
+fn test_all_schemes() {
+    round_trip::<ECDSAAlgorithm>('1');
+    round_trip::<Ed25519Algorithm>('E');
+    round_trip::<Sm2Algorithm>('S');
+}
+
+/// 使用新生成的密钥得到地址
+fn new_address<S: SignatureScheme>() -> String {
+    S::address(&S::derive_public_key(&S::generate_private_key()).unwrap()).unwrap()
+}
+
+#[test]
+fn test_addresses_not_interchangeable() {
+    let ecdsa = new_address::<ECDSAAlgorithm>();
+    let ed25519 = new_address::<Ed25519Algorithm>();
+    let sm2 = new_address::<Sm2Algorithm>();
+
+    assert!(Ed25519Algorithm::validate_address(&ed25519).is_ok());
+    assert!(Sm2Algorithm::validate_address(&sm2).is_ok());
+
+    // 每种方案只接受自己的版本字节
+    for other in [&ecdsa, &sm2] {
+        assert!(Ed25519Algorithm::validate_address(other).is_err());
+    }
+    for other in [&ecdsa, &ed25519] {
+        assert!(Sm2Algorithm::validate_address(other).is_err());
+    }
+    for other in [&ed25519, &sm2] {
+        assert!(ECDSAAlgorithm::validate_address(other, Base58Version::Mainnet).is_err());
+        assert!(ECDSAAlgorithm::parse_address(other).is_err());
+    }
+}
+
+#[test]
+fn test_ed25519_rfc8032_vector() {
+    // RFC 8032 第 7.1 节 TEST 1
+    let priv_key = general_purpose::STANDARD.encode(hex_bytes(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+    ));
+    let pub_key = Ed25519Algorithm::generate_public_key(&priv_key).unwrap();
+    assert_eq!(
+        general_purpose::STANDARD.decode(&pub_key).unwrap(),
+        hex_bytes("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+    );
+
+    let signature = Ed25519Algorithm::sign(&priv_key, b"").unwrap();
+    assert_eq!(
+        general_purpose::STANDARD.decode(&signature).unwrap(),
+        hex_bytes("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b")
+    );
+    assert!(Ed25519Algorithm::verify(&pub_key, b"", &signature).unwrap());
+}
+
+#[test]
+fn test_sm2_user_id() {
+    let data = b"test message";
+    let priv_key = Sm2Algorithm::generate_private_key();
+    let pub_key = Sm2Algorithm::generate_public_key(&priv_key).unwrap();
+    assert_eq!(
+        general_purpose::STANDARD.decode(&pub_key).unwrap().len(),
+        65
+    );
+
+    let signature = Sm2Algorithm::sign_with_id(&priv_key, "ALICE123@YAHOO.COM", data).unwrap();
+    assert!(
+        Sm2Algorithm::verify_with_id(&pub_key, "ALICE123@YAHOO.COM", data, &signature).unwrap()
+    );
+    // 用户标识参与摘要计算，换用默认标识后验证失败
+    assert!(!Sm2Algorithm::verify(&pub_key, data, &signature).unwrap());
+}
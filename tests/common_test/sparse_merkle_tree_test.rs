@@ -0,0 +1,139 @@
+use blockchain_rs::common::algorithm::base_algorithm::HashAlgorithm;
+use blockchain_rs::common::merkle::sparse_merkle_tree::{SparseMerkleProof, SparseMerkleTree};
+
+fn account(i: usize) -> Vec<u8> {
+    format!("account-{}", i).into_bytes()
+}
+
+fn balance(i: usize) -> Vec<u8> {
+    (i as u64 * 1000).to_be_bytes().to_vec()
+}
+
+#[test]
+fn test_get_put_delete() {
+    let mut tree = SparseMerkleTree::default();
+    assert!(tree.is_empty());
+    assert_eq!(tree.root_hash(), vec![0u8; 32]);
+
+    for i in 0..50 {
+        assert_eq!(tree.put(&account(i), balance(i)), None);
+    }
+    assert_eq!(tree.len(), 50);
+    for i in 0..50 {
+        assert_eq!(tree.get(&account(i)), Some(balance(i).as_slice()));
+    }
+    assert_eq!(tree.get(b"missing"), None);
+
+    assert_eq!(tree.put(&account(7), b"updated".to_vec()), Some(balance(7)));
+    assert_eq!(tree.get(&account(7)), Some(&b"updated"[..]));
+    assert_eq!(tree.len(), 50);
+
+    assert_eq!(tree.delete(&account(7)), Some(b"updated".to_vec()));
+    assert_eq!(tree.delete(&account(7)), None);
+    assert_eq!(tree.get(&account(7)), None);
+    assert_eq!(tree.len(), 49);
+
+    for i in 0..50 {
+        tree.delete(&account(i));
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.root_hash(), vec![0u8; 32]);
+}
+
+#[test]
+fn test_root_is_history_independent() {
+    let mut forward = SparseMerkleTree::new(HashAlgorithm::Sha256);
+    let mut backward = SparseMerkleTree::new(HashAlgorithm::Sha256);
+    for i in 0..20 {
+        forward.put(&account(i), balance(i));
+        backward.put(&account(19 - i), balance(19 - i));
+    }
+    assert_eq!(forward.root_hash(), backward.root_hash());
+
+    // 插入后再删除回到原来的根
+    let before = forward.root_hash();
+    forward.put(b"temporary", b"value".to_vec());
+    assert_ne!(forward.root_hash(), before);
+    forward.delete(b"temporary");
+    assert_eq!(forward.root_hash(), before);
+
+    // 值不同则根不同
+    backward.put(&account(3), b"changed".to_vec());
+    assert_ne!(forward.root_hash(), backward.root_hash());
+}
+
+#[test]
+fn test_inclusion_and_non_inclusion_proofs() {
+    let algorithm = HashAlgorithm::Sha256;
+    let mut tree = SparseMerkleTree::new(algorithm);
+    for i in 0..30 {
+        tree.put(&account(i), balance(i));
+    }
+    let root = tree.root_hash();
+
+    for i in 0..30 {
+        let proof = tree.prove(&account(i));
+        assert!(proof.verify_inclusion(algorithm, &root, &account(i), &balance(i)));
+        assert!(!proof.verify_inclusion(algorithm, &root, &account(i), b"forged"));
+        assert!(!proof.verify_non_inclusion(algorithm, &root, &account(i)));
+    }
+
+    for i in 30..60 {
+        let proof = tree.prove(&account(i));
+        assert!(proof.verify_non_inclusion(algorithm, &root, &account(i)));
+        assert!(!proof.verify_inclusion(algorithm, &root, &account(i), &balance(i)));
+    }
+
+    // 包含证明不能冒充其他键的不包含证明
+    let proof = tree.prove(&account(0));
+    let json = serde_json::to_string(&proof).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SparseMerkleProof>(&json).unwrap(),
+        proof
+    );
+    assert!(!proof.verify_inclusion(algorithm, &root, &account(1), &balance(1)));
+
+    let mut tampered = proof.clone();
+    if let Some(sibling) = tampered.siblings.first_mut() {
+        sibling[0] ^= 0x01;
+    }
+    assert!(!tampered.verify_inclusion(algorithm, &root, &account(0), &balance(0)));
+
+    // 空树的不包含证明
+    let empty = SparseMerkleTree::new(algorithm);
+    assert!(empty.prove(b"anything").verify_non_inclusion(
+        algorithm,
+        &empty.root_hash(),
+        b"anything"
+    ));
+}
+
+#[test]
+fn test_snapshots_are_isolated() {
+    let mut tree = SparseMerkleTree::new(HashAlgorithm::Blake3);
+    for i in 0..10 {
+        tree.put(&account(i), balance(i));
+    }
+    let snapshot = tree.snapshot();
+    let snapshot_root = snapshot.root_hash();
+
+    tree.put(&account(0), b"spent".to_vec());
+    tree.delete(&account(1));
+    tree.put(&account(99), balance(99));
+
+    assert_eq!(snapshot.root_hash(), snapshot_root);
+    assert_eq!(snapshot.get(&account(0)), Some(balance(0).as_slice()));
+    assert_eq!(snapshot.get(&account(1)), Some(balance(1).as_slice()));
+    assert_eq!(snapshot.get(&account(99)), None);
+    assert_eq!(snapshot.len(), 10);
+    assert_ne!(tree.root_hash(), snapshot_root);
+
+    // 快照的证明仍对旧根有效
+    let proof = snapshot.prove(&account(0));
+    assert!(proof.verify_inclusion(
+        HashAlgorithm::Blake3,
+        &snapshot_root,
+        &account(0),
+        &balance(0)
+    ));
+}
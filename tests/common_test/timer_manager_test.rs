@@ -1,86 +1,533 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
-use super::*;
-use actix::{Actor, System};
-use tokio::time::{sleep, timeout, Duration};
-use blockchain_rs::common::timer::timer_manager::{Schedule, ScheduleAtFixedRate, ScheduleWithFixedDelay, TimerManager};
+use actix::Actor;
+use std::time::Duration;
+use futures::FutureExt;
+use blockchain_rs::common::timer::clock::{Clock, MockClock};
+use blockchain_rs::common::timer::cron_schedule::CronSchedule;
+use blockchain_rs::common::timer::timer_manager::{Cancel, CancelAll, ListTimers, MissedRunPolicy, RestartPolicy, Schedule, ScheduleAt, ScheduleAtFixedRate, ScheduleCron, ScheduleWithFixedDelay, TimerKind, TimerManager, MAX_CATCH_UP_RUNS};
+use chrono::{TimeZone, Utc};
 
 /// 测试 Schedule 消息，验证任务是否在指定延迟后执行。
 /// Test the Schedule message to verify if the task is executed after the specified delay.
 #[actix_rt::test]
 async fn test_schedule() {
-    let addr = TimerManager.start();
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
     let counter = Arc::new(AtomicU32::new(0));
     let counter_clone = counter.clone();
 
     // 发送 Schedule 消息，100 毫秒后执行任务
     addr.send(Schedule {
+        name: "once".to_string(),
         action: Box::new(move || {
             counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
         }),
         delay: 100,
     }).await.expect("Failed to send Schedule message");
 
-    // 等待一段时间，确保任务有机会执行
-    let result = timeout(Duration::from_millis(200), async {
-        loop {
-            if counter.load(Ordering::SeqCst) == 1 {
-                break;
-            }
-            sleep(Duration::from_millis(10)).await;
-        }
-    }).await;
-
-    // 断言任务成功执行
-    assert!(result.is_ok(), "Task did not execute within the expected time");
+    // 延迟到期前不执行
+    clock.advance(Duration::from_millis(99)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 0, "Task executed before the delay");
 
+    // 断言任务在延迟到期时执行且只执行一次
+    clock.advance(Duration::from_millis(1)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 1, "Task did not execute after the delay");
+    clock.advance(Duration::from_millis(1_000)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 1, "One-shot task executed twice");
 }
 
 /// 测试 ScheduleAtFixedRate 消息，验证任务是否以固定速率周期性执行。
 /// Test the ScheduleAtFixedRate message to verify if the task is executed periodically at a fixed rate.
 #[actix_rt::test]
 async fn test_schedule_at_fixed_rate() {
-    let addr = TimerManager.start();
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
     let counter = Arc::new(AtomicU32::new(0));
     let counter_clone = counter.clone();
 
     // 发送 ScheduleAtFixedRate 消息，初始延迟 100 毫秒，周期 100 毫秒
     addr.send(ScheduleAtFixedRate {
+        name: "fixed-rate".to_string(),
         action: Box::new(move || {
             counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
         }),
         initial_delay: 100,
         period: 100,
+        restart: RestartPolicy::Never,
     }).await.expect("Failed to send ScheduleAtFixedRate message");
 
-    // 等待一段时间，让任务有多次执行机会
-    sleep(Duration::from_millis(500)).await;
+    clock.advance(Duration::from_millis(99)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 0, "Task executed before the initial delay");
 
-    // 断言计数器的值大于等于 3，表明任务至少执行了 3 次
-    assert!(counter.load(Ordering::SeqCst) >= 3, "Task did not execute periodically as expected");
+    // 在 100, 200, 300, 400, 500 毫秒各执行一次
+    clock.advance(Duration::from_millis(401)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 5, "Task did not execute periodically as expected");
 }
 
 /// 测试 ScheduleWithFixedDelay 消息，验证任务是否以固定延迟周期性执行。
 /// Test the ScheduleWithFixedDelay message to verify if the task is executed periodically with a fixed delay.
 #[actix_rt::test]
 async fn test_schedule_with_fixed_delay() {
-    let addr = TimerManager.start();
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
     let counter = Arc::new(AtomicU32::new(0));
     let counter_clone = counter.clone();
 
     // 发送 ScheduleWithFixedDelay 消息，初始延迟 100 毫秒，周期 100 毫秒
     addr.send(ScheduleWithFixedDelay {
+        name: "fixed-delay".to_string(),
         action: Box::new(move || {
             counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
         }),
         initial_delay: 100,
         period: 100,
+        restart: RestartPolicy::Never,
+    }).await.expect("Failed to send ScheduleWithFixedDelay message");
+
+    clock.advance(Duration::from_millis(99)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 0, "Task executed before the initial delay");
+
+    // 动作立即完成，因此在 100, 200, 300, 400, 500 毫秒各执行一次
+    clock.advance(Duration::from_millis(401)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 5, "Task did not execute periodically as expected");
+}
+
+/// 测试 Cancel 消息，验证被取消的一次性任务不会执行，周期任务停止执行。
+/// Test the Cancel message: a cancelled one-shot task never runs and a periodic task stops.
+#[actix_rt::test]
+async fn test_cancel() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+
+    // 一次性任务在触发前被取消
+    let counter_clone = counter.clone();
+    let handle = addr.send(Schedule {
+        name: "once".to_string(),
+        action: Box::new(move || {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        delay: 100,
+    }).await.expect("Failed to send Schedule message");
+    assert!(addr.send(Cancel { id: handle.id() }).await.unwrap());
+    assert!(handle.is_cancelled());
+    // 重复取消返回 false
+    assert!(!addr.send(Cancel { id: handle.id() }).await.unwrap());
+
+    // 周期任务运行一段时间后通过句柄取消
+    let counter_clone = counter.clone();
+    let periodic = addr.send(ScheduleAtFixedRate {
+        name: "fixed-rate".to_string(),
+        action: Box::new(move || {
+            counter_clone.fetch_add(10, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        initial_delay: 0,
+        period: 50,
+        restart: RestartPolicy::Never,
+    }).await.expect("Failed to send ScheduleAtFixedRate message");
+    assert_ne!(periodic.id(), handle.id());
+
+    // 周期任务在 0, 50, 100 毫秒执行，被取消的一次性任务没有执行
+    clock.advance(Duration::from_millis(120)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 30);
+    periodic.cancel();
+
+    clock.advance(Duration::from_millis(200)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 30, "Task ran after cancellation");
+    assert_eq!(clock.pending_sleeps(), 0);
+    // 已结束的任务不再被 TimerManager 记录
+    assert!(!addr.send(Cancel { id: periodic.id() }).await.unwrap());
+}
+
+/// 测试 CancelAll 消息，验证所有任务被取消。
+/// Test the CancelAll message to verify that every task is cancelled.
+#[actix_rt::test]
+async fn test_cancel_all() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+
+    for _ in 0..3 {
+        let counter_clone = counter.clone();
+        addr.send(ScheduleWithFixedDelay {
+            name: "fixed-delay".to_string(),
+            action: Box::new(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                async {}.boxed()
+            }),
+            initial_delay: 100,
+            period: 100,
+            restart: RestartPolicy::Never,
+        }).await.expect("Failed to send ScheduleWithFixedDelay message");
+    }
+
+    assert_eq!(addr.send(CancelAll).await.unwrap(), 3);
+    clock.advance(Duration::from_millis(250)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 0, "Task ran after CancelAll");
+    assert_eq!(clock.pending_sleeps(), 0);
+    assert_eq!(addr.send(CancelAll).await.unwrap(), 0);
+}
+
+/// 测试异步动作，验证动作中的 await 正常完成。
+/// Test async actions to verify that awaits inside the action complete.
+#[actix_rt::test]
+async fn test_async_action() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = counter.clone();
+    let action_clock = clock.clone();
+
+    addr.send(ScheduleWithFixedDelay {
+        name: "fixed-delay".to_string(),
+        action: Box::new(move || {
+            let counter = counter_clone.clone();
+            let clock = action_clock.clone();
+            async move {
+                // 模拟异步 I/O
+                clock.sleep(Duration::from_millis(20)).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            .boxed()
+        }),
+        initial_delay: 0,
+        period: 30,
+        restart: RestartPolicy::Never,
     }).await.expect("Failed to send ScheduleWithFixedDelay message");
 
-    // 等待一段时间，让任务有多次执行机会
-    sleep(Duration::from_millis(500)).await;
+    clock.advance(Duration::from_millis(19)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 0, "Async action completed before its await");
+
+    // 动作在 20, 70, 120, 170, 220, 270 毫秒完成
+    clock.advance(Duration::from_millis(281)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 6, "Async action did not complete periodically");
+}
+
+/// 测试 panic 隔离与重启策略。
+/// Test panic isolation and restart policies.
+#[actix_rt::test]
+async fn test_panic_isolation_and_restart_policy() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let panicking = |counter: Arc<AtomicU32>| -> Box<dyn Fn() -> futures::future::BoxFuture<'static, ()> + Send> {
+        Box::new(move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                panic!("action failed");
+            }
+            .boxed()
+        })
+    };
+
+    let never = Arc::new(AtomicU32::new(0));
+    let limited = Arc::new(AtomicU32::new(0));
+    let always = Arc::new(AtomicU32::new(0));
+    for (counter, restart) in [
+        (&never, RestartPolicy::Never),
+        (&limited, RestartPolicy::Limited(2)),
+        (&always, RestartPolicy::Always),
+    ] {
+        addr.send(ScheduleAtFixedRate {
+            name: "fixed-rate".to_string(),
+            action: panicking(counter.clone()),
+            initial_delay: 0,
+            period: 20,
+            restart,
+        }).await.expect("Failed to send ScheduleAtFixedRate message");
+    }
+
+    // 一次性任务的 panic 同样被捕获
+    addr.send(Schedule {
+        name: "once".to_string(),
+        action: Box::new(|| async { panic!("one-shot failed") }.boxed()),
+        delay: 0,
+    }).await.expect("Failed to send Schedule message");
+
+    // Always 任务在 0, 20, ..., 300 毫秒各执行一次
+    clock.advance(Duration::from_millis(300)).await;
+    assert_eq!(never.load(Ordering::SeqCst), 1, "Never policy restarted the task");
+    assert_eq!(limited.load(Ordering::SeqCst), 3, "Limited(2) policy should run three times");
+    assert_eq!(always.load(Ordering::SeqCst), 16, "Always policy stopped the task");
+
+    // TimerManager 仍然正常工作，只剩 Always 任务
+    assert_eq!(addr.send(CancelAll).await.unwrap(), 1);
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = counter.clone();
+    addr.send(Schedule {
+        name: "once".to_string(),
+        action: Box::new(move || {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        delay: 10,
+    }).await.expect("Failed to send Schedule message");
+    clock.advance(Duration::from_millis(10)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+    assert_eq!(always.load(Ordering::SeqCst), 16, "Task ran after CancelAll");
+}
+
+/// 使用模拟时钟确定性地测试三种调度，不依赖真实等待。
+/// Deterministically test the three kinds of schedule with a mock clock, without real waits.
+#[actix_rt::test]
+async fn test_mock_clock_schedules() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let once = Arc::new(AtomicU32::new(0));
+    let rate = Arc::new(AtomicU32::new(0));
+    let delay = Arc::new(AtomicU32::new(0));
+
+    let once_clone = once.clone();
+    addr.send(Schedule {
+        name: "once".to_string(),
+        action: Box::new(move || {
+            once_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        delay: 1_000,
+    }).await.unwrap();
+
+    let rate_clone = rate.clone();
+    addr.send(ScheduleAtFixedRate {
+        name: "fixed-rate".to_string(),
+        action: Box::new(move || {
+            rate_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        initial_delay: 500,
+        period: 1_000,
+        restart: RestartPolicy::Never,
+    }).await.unwrap();
+
+    // 动作本身通过时钟等待 300 毫秒，因此固定延迟任务的间隔为 1300 毫秒
+    let delay_clone = delay.clone();
+    let action_clock = clock.clone();
+    addr.send(ScheduleWithFixedDelay {
+        name: "fixed-delay".to_string(),
+        action: Box::new(move || {
+            let delay = delay_clone.clone();
+            let clock = action_clock.clone();
+            async move {
+                clock.sleep(Duration::from_millis(300)).await;
+                delay.fetch_add(1, Ordering::SeqCst);
+            }
+            .boxed()
+        }),
+        initial_delay: 0,
+        period: 1_000,
+        restart: RestartPolicy::Never,
+    }).await.unwrap();
+
+    clock.advance(Duration::from_millis(999)).await;
+    assert_eq!(once.load(Ordering::SeqCst), 0);
+    assert_eq!(rate.load(Ordering::SeqCst), 1);
+    assert_eq!(delay.load(Ordering::SeqCst), 1);
+
+    clock.advance(Duration::from_millis(1)).await;
+    assert_eq!(once.load(Ordering::SeqCst), 1);
+
+    // 10 秒：固定速率在 500, 1500, ..., 9500 触发；固定延迟在 300, 1600, 2900, ..., 9400 完成
+    clock.advance(Duration::from_millis(9_000)).await;
+    assert_eq!(once.load(Ordering::SeqCst), 1);
+    assert_eq!(rate.load(Ordering::SeqCst), 10);
+    assert_eq!(delay.load(Ordering::SeqCst), 8);
+
+    assert_eq!(addr.send(CancelAll).await.unwrap(), 2);
+    clock.advance(Duration::from_millis(10_000)).await;
+    assert_eq!(rate.load(Ordering::SeqCst), 10);
+    assert_eq!(delay.load(Ordering::SeqCst), 8);
+}
+
+/// 测试 ScheduleAt 消息，验证任务在指定墙上时间执行，已过去的时间立即执行。
+/// Test the ScheduleAt message to verify the task runs at the wall-clock time, immediately if
+/// the time has passed.
+#[actix_rt::test]
+async fn test_schedule_at() {
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = MockClock::starting_at(start);
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+
+    for at in [start + chrono::Duration::minutes(10), start - chrono::Duration::minutes(10)] {
+        let counter_clone = counter.clone();
+        addr.send(ScheduleAt {
+            name: "at".to_string(),
+            action: Box::new(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                async {}.boxed()
+            }),
+            at,
+        }).await.unwrap();
+    }
+
+    clock.advance(Duration::from_secs(599)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+    clock.advance(Duration::from_secs(1)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+/// 测试 ScheduleCron 消息及暂停后错过触发的跳过与补执行策略。
+/// Test the ScheduleCron message and the skip and catch-up policies for runs missed in a pause.
+#[actix_rt::test]
+async fn test_schedule_cron_missed_runs() {
+    let clock = MockClock::starting_at(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let skip = Arc::new(AtomicU32::new(0));
+    let catch_up = Arc::new(AtomicU32::new(0));
+
+    for (counter, missed) in [(&skip, MissedRunPolicy::Skip), (&catch_up, MissedRunPolicy::CatchUp)] {
+        let counter_clone = counter.clone();
+        addr.send(ScheduleCron {
+            name: "cron".to_string(),
+            action: Box::new(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                async {}.boxed()
+            }),
+            // 每小时整点 / Top of every hour
+            schedule: CronSchedule::utc("0 0 * * * *").unwrap(),
+            missed,
+            restart: RestartPolicy::Never,
+        }).await.unwrap();
+    }
+
+    // 01:00、02:00、03:00 正常触发
+    clock.advance(Duration::from_secs(3 * 3600)).await;
+    assert_eq!(skip.load(Ordering::SeqCst), 3);
+    assert_eq!(catch_up.load(Ordering::SeqCst), 3);
+
+    // 暂停到 08:00，错过 04:00 至 08:00 的五次触发
+    clock.jump(Duration::from_secs(5 * 3600)).await;
+    assert_eq!(skip.load(Ordering::SeqCst), 4);
+    assert_eq!(catch_up.load(Ordering::SeqCst), 8);
+
+    // 恢复后继续按整点触发
+    clock.advance(Duration::from_secs(3600)).await;
+    assert_eq!(skip.load(Ordering::SeqCst), 5);
+    assert_eq!(catch_up.load(Ordering::SeqCst), 9);
+}
+
+/// 测试长时间暂停后补执行次数不超过 MAX_CATCH_UP_RUNS。
+/// Test that catching up after a long pause runs at most MAX_CATCH_UP_RUNS times.
+#[actix_rt::test]
+async fn test_schedule_cron_catch_up_limit() {
+    let clock = MockClock::starting_at(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = counter.clone();
+
+    addr.send(ScheduleCron {
+        name: "cron".to_string(),
+        action: Box::new(move || {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        // 每秒 / Every second
+        schedule: CronSchedule::utc("* * * * * *").unwrap(),
+        missed: MissedRunPolicy::CatchUp,
+        restart: RestartPolicy::Never,
+    }).await.unwrap();
+
+    // 暂停一天，错过86400次触发，只补执行 MAX_CATCH_UP_RUNS 次
+    clock.jump(Duration::from_secs(24 * 3600)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), MAX_CATCH_UP_RUNS as u32);
+
+    // 其余被丢弃，之后继续按秒触发
+    clock.advance(Duration::from_secs(1)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), MAX_CATCH_UP_RUNS as u32 + 1);
+}
+
+/// 测试 ListTimers 消息，验证任务信息、运行统计、超时计数和 panic 信息。
+/// Test the ListTimers message to verify task information, run statistics, overrun counters and
+/// panic messages.
+#[actix_rt::test]
+async fn test_list_timers() {
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = MockClock::starting_at(start);
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+
+    // 动作耗时 150 毫秒，超过 100 毫秒的周期
+    let action_clock = clock.clone();
+    let rate = addr.send(ScheduleAtFixedRate {
+        name: "stats-rollup".to_string(),
+        action: Box::new(move || action_clock.sleep(Duration::from_millis(150))),
+        initial_delay: 0,
+        period: 100,
+        restart: RestartPolicy::Never,
+    }).await.unwrap();
+    addr.send(Schedule {
+        name: "timeout".to_string(),
+        action: Box::new(|| async {}.boxed()),
+        delay: 1_000,
+    }).await.unwrap();
+    addr.send(ScheduleCron {
+        name: "checkpoint".to_string(),
+        action: Box::new(|| async { panic!("checkpoint failed") }.boxed()),
+        schedule: CronSchedule::utc("0 0 * * * *").unwrap(),
+        missed: MissedRunPolicy::Skip,
+        restart: RestartPolicy::Always,
+    }).await.unwrap();
+
+    let timers = addr.send(ListTimers).await.unwrap();
+    let names: Vec<_> = timers.iter().map(|timer| timer.name.as_str()).collect();
+    assert_eq!(names, ["stats-rollup", "timeout", "checkpoint"]);
+    assert_eq!(timers[0].kind, TimerKind::FixedRate { period: 100 });
+    assert_eq!(timers[1].kind, TimerKind::Once);
+    assert_eq!(timers[1].next_fire, Some(start + chrono::Duration::seconds(1)));
+    assert_eq!(timers[2].kind, TimerKind::Cron { expression: "0 0 * * * *".to_string() });
+    assert_eq!(timers[2].next_fire, Some(start + chrono::Duration::hours(1)));
+    assert!(timers.iter().all(|timer| timer.run_count == 0 && timer.last_duration.is_none()));
+
+    // 一次性任务结束后不再列出，固定速率任务每次执行都超时
+    clock.advance(Duration::from_millis(1_000)).await;
+    let timers = addr.send(ListTimers).await.unwrap();
+    assert_eq!(timers.len(), 2);
+    let stats = &timers[0];
+    assert_eq!(stats.id, rate.id());
+    assert!(stats.run_count >= 5, "fixed-rate task ran {} times", stats.run_count);
+    assert_eq!(stats.overruns, stats.run_count);
+    assert_eq!(stats.last_duration, Some(Duration::from_millis(150)));
+    assert_eq!(stats.last_error, None);
 
-    // 断言计数器的值大于等于 3，表明任务至少执行了 3 次
-    assert!(counter.load(Ordering::SeqCst) >= 3, "Task did not execute periodically as expected");
+    assert!(addr.send(Cancel { id: rate.id() }).await.unwrap());
+    clock.advance(Duration::from_secs(3_600)).await;
+    let timers = addr.send(ListTimers).await.unwrap();
+    assert_eq!(timers.len(), 1);
+    let checkpoint = &timers[0];
+    assert_eq!(checkpoint.run_count, 1);
+    assert_eq!(checkpoint.overruns, 0);
+    assert_eq!(checkpoint.last_error.as_deref(), Some("checkpoint failed"));
+    assert_eq!(checkpoint.next_fire, Some(start + chrono::Duration::hours(2)));
+}
 
-}
\ No newline at end of file
+/// 测试零周期的固定速率任务不会终止 TimerManager，周期按1毫秒处理。
+/// Test that a zero-period fixed-rate task does not stop the TimerManager; the period is treated
+/// as 1 millisecond.
+#[actix_rt::test]
+async fn test_zero_period_fixed_rate() {
+    let clock = MockClock::new();
+    let addr = TimerManager::with_clock(Arc::new(clock.clone())).start();
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = counter.clone();
+
+    addr.send(ScheduleAtFixedRate {
+        name: "zero".to_string(),
+        action: Box::new(move || {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            async {}.boxed()
+        }),
+        initial_delay: 0,
+        period: 0,
+        restart: RestartPolicy::Never,
+    }).await.unwrap();
+
+    clock.advance(Duration::from_millis(10)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 11);
+    let timers = addr.send(ListTimers).await.expect("TimerManager stopped");
+    assert_eq!(timers[0].kind, TimerKind::FixedRate { period: 1 });
+}